@@ -0,0 +1,56 @@
+use common::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::fs;
+use std::path::PathBuf;
+
+fn input(day: u8) -> String {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{:02}", day))
+        .join("input")
+        .join("p1.txt");
+
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("Could not read {}: {}", path.display(), e))
+}
+
+fn bench_solution<S: Solution>(c: &mut Criterion) {
+    let input = input(S::DAY);
+    let parsed = S::parse(&input).unwrap();
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+
+    group.sample_size(10);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_solution::<day01::Day01>,
+    bench_solution::<day02::Day02>,
+    bench_solution::<day03::Day03>,
+    bench_solution::<day04::Day04>,
+    bench_solution::<day05::Day05>,
+    bench_solution::<day06::Day06>,
+    bench_solution::<day07::Day07>,
+    bench_solution::<day08::Day08>,
+    bench_solution::<day09::Day09>,
+    bench_solution::<day10::Day10>,
+    bench_solution::<day11::Day11>,
+    bench_solution::<day12::Day12>,
+    bench_solution::<day13::Day13>,
+    bench_solution::<day14::Day14>,
+    bench_solution::<day15::Day15>,
+    bench_solution::<day16::Day16>,
+    bench_solution::<day17::Day17>,
+    bench_solution::<day18::Day18>,
+    bench_solution::<day19::Day19>,
+    bench_solution::<day20::Day20>,
+    bench_solution::<day21::Day21>,
+    bench_solution::<day22::Day22>,
+    bench_solution::<day23::Day23>,
+    bench_solution::<day24::Day24>,
+    bench_solution::<day25::Day25>
+);
+criterion_main!(benches);
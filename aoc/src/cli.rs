@@ -0,0 +1,237 @@
+use crate::output::Format;
+use common::Part;
+use std::path::PathBuf;
+
+pub const USAGE: &str = "Usage:
+    aoc run <day|all> [--part <1|2>] [--input <path>] [--format <text|json>]
+    aoc bench <day|all> [--samples <n>]
+    aoc verify [<answers.toml|answers.json>]";
+
+const DEFAULT_SAMPLES: usize = 10;
+const DEFAULT_ANSWERS: &str = "answers.toml";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Days {
+    Single(u8),
+    All,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunOptions {
+    pub days: Days,
+    pub part: Option<Part>,
+    pub input: Option<PathBuf>,
+    pub format: Format,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchOptions {
+    pub days: Days,
+    pub samples: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifyOptions {
+    pub answers: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Run(RunOptions),
+    Bench(BenchOptions),
+    Verify(VerifyOptions),
+}
+
+fn parse_days(value: &str) -> Result<Days, String> {
+    if value == "all" {
+        return Ok(Days::All);
+    }
+
+    match value.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(Days::Single(day)),
+        _ => Err(format!("Invalid day '{}', expected 1-25 or 'all'", value)),
+    }
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("Invalid part '{}', expected 1 or 2", value)),
+    }
+}
+
+fn parse_format(value: &str) -> Result<Format, String> {
+    match value {
+        "text" => Ok(Format::Text),
+        "json" => Ok(Format::Json),
+        _ => Err(format!("Invalid format '{}', expected text or json", value)),
+    }
+}
+
+fn parse_run<I>(mut args: I) -> Result<RunOptions, String>
+where
+    I: Iterator<Item = String>,
+{
+    let days = parse_days(&args.next().ok_or("Missing day")?)?;
+    let mut options = RunOptions {
+        days,
+        part: None,
+        input: None,
+        format: Format::Text,
+    };
+
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value for {}", flag))?;
+
+        match flag.as_str() {
+            "--part" | "-p" => options.part = Some(parse_part(&value)?),
+            "--input" | "-i" => options.input = Some(PathBuf::from(value)),
+            "--format" | "-f" => options.format = parse_format(&value)?,
+            _ => return Err(format!("Unknown option '{}'", flag)),
+        }
+    }
+
+    if options.days == Days::All && options.input.is_some() {
+        return Err("--input can only be used with a single day".to_string());
+    }
+
+    Ok(options)
+}
+
+fn parse_bench<I>(mut args: I) -> Result<BenchOptions, String>
+where
+    I: Iterator<Item = String>,
+{
+    let days = parse_days(&args.next().ok_or("Missing day")?)?;
+    let mut options = BenchOptions {
+        days,
+        samples: DEFAULT_SAMPLES,
+    };
+
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value for {}", flag))?;
+
+        match flag.as_str() {
+            "--samples" | "-s" => {
+                options.samples = match value.parse::<usize>() {
+                    Ok(samples) if samples > 0 => samples,
+                    _ => return Err(format!("Invalid samples '{}'", value)),
+                }
+            }
+            _ => return Err(format!("Unknown option '{}'", flag)),
+        }
+    }
+
+    Ok(options)
+}
+
+fn parse_verify<I>(mut args: I) -> Result<VerifyOptions, String>
+where
+    I: Iterator<Item = String>,
+{
+    let answers = args.next().unwrap_or_else(|| DEFAULT_ANSWERS.to_string());
+
+    if let Some(arg) = args.next() {
+        return Err(format!("Unexpected argument '{}'", arg));
+    }
+
+    Ok(VerifyOptions {
+        answers: PathBuf::from(answers),
+    })
+}
+
+pub fn parse<I>(mut args: I) -> Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    match args.next().as_deref() {
+        Some("run") => Ok(Command::Run(parse_run(args)?)),
+        Some("bench") => Ok(Command::Bench(parse_bench(args)?)),
+        Some("verify") => Ok(Command::Verify(parse_verify(args)?)),
+        Some(command) => Err(format!("Unknown command '{}'", command)),
+        None => Err("Missing command".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_args(args: &str) -> Result<Command, String> {
+        parse(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn parses_run_options() {
+        assert_eq!(
+            parse_args("run 7 --part 2 -i example.txt"),
+            Ok(Command::Run(RunOptions {
+                days: Days::Single(7),
+                part: Some(Part::Two),
+                input: Some(PathBuf::from("example.txt")),
+                format: Format::Text,
+            }))
+        );
+        assert_eq!(
+            parse_args("run all --format json"),
+            Ok(Command::Run(RunOptions {
+                days: Days::All,
+                part: None,
+                input: None,
+                format: Format::Json,
+            }))
+        );
+    }
+
+    #[test]
+    fn parses_bench_options() {
+        assert_eq!(
+            parse_args("bench all"),
+            Ok(Command::Bench(BenchOptions {
+                days: Days::All,
+                samples: DEFAULT_SAMPLES,
+            }))
+        );
+        assert_eq!(
+            parse_args("bench 15 --samples 3"),
+            Ok(Command::Bench(BenchOptions {
+                days: Days::Single(15),
+                samples: 3,
+            }))
+        );
+    }
+
+    #[test]
+    fn parses_verify_options() {
+        assert_eq!(
+            parse_args("verify"),
+            Ok(Command::Verify(VerifyOptions {
+                answers: PathBuf::from(DEFAULT_ANSWERS),
+            }))
+        );
+        assert_eq!(
+            parse_args("verify expected.json"),
+            Ok(Command::Verify(VerifyOptions {
+                answers: PathBuf::from("expected.json"),
+            }))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        assert!(parse_args("run 0").is_err());
+        assert!(parse_args("run 1 --part 3").is_err());
+        assert!(parse_args("run 1 --part").is_err());
+        assert!(parse_args("run 1 --format yaml").is_err());
+        assert!(parse_args("run all --input example.txt").is_err());
+        assert!(parse_args("bench 1 --samples 0").is_err());
+        assert!(parse_args("bench 1 --part 1").is_err());
+        assert!(parse_args("verify a.toml b.toml").is_err());
+        assert!(parse_args("walk 1").is_err());
+    }
+}
@@ -0,0 +1,186 @@
+use cli::{BenchOptions, Command, Days, RunOptions, VerifyOptions};
+use common::input::{self, Source};
+use common::{Answer, Part};
+use output::Format;
+use solutions::{BENCHMARKS, SOLUTIONS};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Duration;
+
+mod cli;
+mod output;
+mod report;
+mod solutions;
+mod verify;
+
+fn input_path(day: u8, name: &str) -> PathBuf {
+    input::default_path(day).with_file_name(name)
+}
+
+fn read_input(path: &Path) -> Result<String, String> {
+    Source::Path(path.to_path_buf())
+        .read()
+        .map_err(|e| format!("Could not read {}: {}", path.display(), e))
+}
+
+fn days(days: Days) -> Vec<u8> {
+    match days {
+        Days::Single(day) => vec![day],
+        Days::All => (1..=25).collect(),
+    }
+}
+
+fn run(options: &RunOptions) -> Result<(), String> {
+    let parts = match options.part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+
+    let mut answers = Vec::new();
+
+    for day in days(options.days) {
+        let path = match &options.input {
+            Some(path) => path.clone(),
+            None => input::default_path(day),
+        };
+        // An unreadable input fails every part of that day, so each part still
+        // gets a record and later days still run.
+        let day_answers = match Source::Path(path.clone()).load() {
+            Ok(input) => SOLUTIONS[day as usize - 1](&input, &parts),
+            Err(error) => parts
+                .iter()
+                .map(|&part| Answer {
+                    day,
+                    part,
+                    value: Err(error.clone()),
+                    elapsed: Duration::ZERO,
+                })
+                .collect(),
+        };
+
+        for answer in day_answers {
+            if options.format == Format::Text {
+                match answer.value {
+                    Ok(_) => println!("{}", output::text(&answer)),
+                    Err(_) => eprintln!("{}: {}", path.display(), output::text(&answer)),
+                }
+            }
+
+            answers.push(answer);
+        }
+    }
+
+    if options.format == Format::Json {
+        println!("{}", output::json(&answers));
+    }
+
+    let failures = answers
+        .iter()
+        .filter(|answer| answer.value.is_err())
+        .count();
+
+    match failures {
+        0 => Ok(()),
+        1 => Err(String::from("1 part failed")),
+        _ => Err(format!("{} parts failed", failures)),
+    }
+}
+
+fn bench(options: &BenchOptions) -> Result<(), String> {
+    let mut timings = Vec::new();
+
+    for day in days(options.days) {
+        let path = input::default_path(day);
+        let input = read_input(&path)?;
+        let timing = BENCHMARKS[day as usize - 1](&input, options.samples)
+            .map_err(|error| format!("Day {:02}: {}: {}", day, path.display(), error))?;
+
+        timings.push(timing);
+    }
+
+    print!("{}", report::markdown(&timings));
+    Ok(())
+}
+
+fn verify(options: &VerifyOptions) -> Result<(), String> {
+    let contents = read_input(&options.answers)?;
+    let expectations = verify::parse(&options.answers, &contents)?;
+    let mut inputs: BTreeMap<(u8, &str), Vec<&verify::Expectation>> = BTreeMap::new();
+    let mut failures = 0;
+
+    for expectation in &expectations {
+        inputs
+            .entry((expectation.day, &expectation.input))
+            .or_default()
+            .push(expectation);
+    }
+
+    for ((day, name), expected) in inputs {
+        let parts: Vec<Part> = expected
+            .iter()
+            .map(|expectation| expectation.part)
+            .collect();
+        let actual: Vec<String> = match read_input(&input_path(day, name)) {
+            Ok(input) => SOLUTIONS[day as usize - 1](&input, &parts)
+                .into_iter()
+                .map(|answer| match answer.value {
+                    Ok(value) => value,
+                    Err(error) => format!("error: {}", error),
+                })
+                .collect(),
+            Err(error) => vec![format!("error: {}", error); parts.len()],
+        };
+
+        for (expectation, actual) in expected.iter().zip(actual) {
+            let label = format!(
+                "Day {:02} part {} ({})",
+                day,
+                expectation.part.number(),
+                name
+            );
+
+            if actual == expectation.answer {
+                println!("ok   {}", label);
+            } else {
+                println!(
+                    "FAIL {}\n    expected: {}\n    actual:   {}",
+                    label, expectation.answer, actual
+                );
+                failures += 1;
+            }
+        }
+    }
+
+    println!(
+        "\n{} passed, {} failed",
+        expectations.len() - failures,
+        failures
+    );
+
+    match failures {
+        0 => Ok(()),
+        _ => Err(String::from("Verification failed")),
+    }
+}
+
+fn main() {
+    let command = match cli::parse(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, cli::USAGE);
+            process::exit(2);
+        }
+    };
+
+    let result = match command {
+        Command::Run(options) => run(&options),
+        Command::Bench(options) => bench(&options),
+        Command::Verify(options) => verify(&options),
+    };
+
+    if let Err(error) = result {
+        eprintln!("{}", error);
+        process::exit(1);
+    }
+}
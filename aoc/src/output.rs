@@ -0,0 +1,103 @@
+use common::Answer;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    answer: Option<&'a str>,
+    elapsed: f64,
+    error: Option<String>,
+}
+
+impl<'a> From<&'a Answer> for Record<'a> {
+    fn from(answer: &'a Answer) -> Self {
+        Self {
+            day: answer.day,
+            part: answer.part.number(),
+            answer: answer.value.as_deref().ok(),
+            elapsed: answer.elapsed.as_secs_f64(),
+            error: answer.value.as_ref().err().map(|error| error.to_string()),
+        }
+    }
+}
+
+pub fn text(answer: &Answer) -> String {
+    let label = format!("Day {:02} part {}", answer.day, answer.part.number());
+
+    match &answer.value {
+        Ok(value) => format!("{}: {}", label, value),
+        Err(error) => format!("{}: {}", label, error),
+    }
+}
+
+pub fn json(answers: &[Answer]) -> String {
+    let records: Vec<Record> = answers.iter().map(Record::from).collect();
+    serde_json::to_string_pretty(&records).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{Error, Part};
+    use std::time::Duration;
+
+    fn answers() -> Vec<Answer> {
+        vec![
+            Answer {
+                day: 21,
+                part: Part::Two,
+                value: Ok(String::from("mxmxvkd,sqjhc,fvjkl")),
+                elapsed: Duration::from_millis(250),
+            },
+            Answer {
+                day: 9,
+                part: Part::One,
+                value: Err(Error::parse("Invalid value 'x'").at_line(3)),
+                elapsed: Duration::from_micros(5),
+            },
+        ]
+    }
+
+    #[test]
+    fn renders_text() {
+        let answers = answers();
+
+        assert_eq!(text(&answers[0]), "Day 21 part 2: mxmxvkd,sqjhc,fvjkl");
+        assert_eq!(
+            text(&answers[1]),
+            "Day 09 part 1: line 3: Invalid value 'x'"
+        );
+    }
+
+    #[test]
+    fn renders_json() {
+        let value: serde_json::Value = serde_json::from_str(&json(&answers())).unwrap();
+
+        assert_eq!(
+            value,
+            serde_json::json!([
+                {
+                    "day": 21,
+                    "part": 2,
+                    "answer": "mxmxvkd,sqjhc,fvjkl",
+                    "elapsed": 0.25,
+                    "error": null
+                },
+                {
+                    "day": 9,
+                    "part": 1,
+                    "answer": null,
+                    "elapsed": 0.000005,
+                    "error": "line 3: Invalid value 'x'"
+                }
+            ])
+        );
+    }
+}
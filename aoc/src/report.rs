@@ -0,0 +1,73 @@
+use common::Timings;
+use std::time::Duration;
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+
+    if nanos < 1e3 {
+        format!("{} ns", nanos)
+    } else if nanos < 1e6 {
+        format!("{:.1} µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.2} ms", nanos / 1e6)
+    } else {
+        format!("{:.2} s", nanos / 1e9)
+    }
+}
+
+pub fn markdown(timings: &[Timings]) -> String {
+    let mut table = String::from("| Day | Parse | Part 1 | Part 2 | Total |\n");
+    table.push_str("|----:|------:|-------:|-------:|------:|\n");
+
+    for timing in timings {
+        table.push_str(&format!(
+            "| {:02} | {} | {} | {} | {} |\n",
+            timing.day,
+            format_duration(timing.parse),
+            format_duration(timing.part1),
+            format_duration(timing.part2),
+            format_duration(timing.total())
+        ));
+    }
+
+    let total: Duration = timings.iter().map(Timings::total).sum();
+    table.push_str(&format!(
+        "| **Total** | | | | **{}** |\n",
+        format_duration(total)
+    ));
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(Duration::from_nanos(420)), "420 ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.3 µs");
+        assert_eq!(format_duration(Duration::from_micros(4_560)), "4.56 ms");
+        assert_eq!(format_duration(Duration::from_millis(1_230)), "1.23 s");
+    }
+
+    #[test]
+    fn renders_a_row_per_day() {
+        let timings = [Timings {
+            day: 3,
+            parse: Duration::from_nanos(0),
+            part1: Duration::from_micros(10),
+            part2: Duration::from_micros(20),
+        }];
+
+        assert_eq!(
+            markdown(&timings).lines().collect::<Vec<_>>(),
+            vec![
+                "| Day | Parse | Part 1 | Part 2 | Total |",
+                "|----:|------:|-------:|-------:|------:|",
+                "| 03 | 0 ns | 10.0 µs | 20.0 µs | 30.0 µs |",
+                "| **Total** | | | | **30.0 µs** |",
+            ]
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use common::{bench, run, Bencher, Runner};
+
+pub const SOLUTIONS: [Runner; 25] = [
+    run::<day01::Day01>,
+    run::<day02::Day02>,
+    run::<day03::Day03>,
+    run::<day04::Day04>,
+    run::<day05::Day05>,
+    run::<day06::Day06>,
+    run::<day07::Day07>,
+    run::<day08::Day08>,
+    run::<day09::Day09>,
+    run::<day10::Day10>,
+    run::<day11::Day11>,
+    run::<day12::Day12>,
+    run::<day13::Day13>,
+    run::<day14::Day14>,
+    run::<day15::Day15>,
+    run::<day16::Day16>,
+    run::<day17::Day17>,
+    run::<day18::Day18>,
+    run::<day19::Day19>,
+    run::<day20::Day20>,
+    run::<day21::Day21>,
+    run::<day22::Day22>,
+    run::<day23::Day23>,
+    run::<day24::Day24>,
+    run::<day25::Day25>,
+];
+
+pub const BENCHMARKS: [Bencher; 25] = [
+    bench::<day01::Day01>,
+    bench::<day02::Day02>,
+    bench::<day03::Day03>,
+    bench::<day04::Day04>,
+    bench::<day05::Day05>,
+    bench::<day06::Day06>,
+    bench::<day07::Day07>,
+    bench::<day08::Day08>,
+    bench::<day09::Day09>,
+    bench::<day10::Day10>,
+    bench::<day11::Day11>,
+    bench::<day12::Day12>,
+    bench::<day13::Day13>,
+    bench::<day14::Day14>,
+    bench::<day15::Day15>,
+    bench::<day16::Day16>,
+    bench::<day17::Day17>,
+    bench::<day18::Day18>,
+    bench::<day19::Day19>,
+    bench::<day20::Day20>,
+    bench::<day21::Day21>,
+    bench::<day22::Day22>,
+    bench::<day23::Day23>,
+    bench::<day24::Day24>,
+    bench::<day25::Day25>,
+];
@@ -0,0 +1,130 @@
+use common::Part;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Value {
+    Integer(i64),
+    Text(String),
+}
+
+type Document = BTreeMap<String, BTreeMap<String, BTreeMap<String, Value>>>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expectation {
+    pub day: u8,
+    pub input: String,
+    pub part: Part,
+    pub answer: String,
+}
+
+fn parse_day(key: &str) -> Result<u8, String> {
+    match key.strip_prefix("day").map(str::parse::<u8>) {
+        Some(Ok(day)) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("Invalid day '{}', expected day01-day25", key)),
+    }
+}
+
+fn parse_part(key: &str) -> Result<Part, String> {
+    match key {
+        "part1" => Ok(Part::One),
+        "part2" => Ok(Part::Two),
+        _ => Err(format!("Invalid part '{}', expected part1 or part2", key)),
+    }
+}
+
+fn expectations(document: Document) -> Result<Vec<Expectation>, String> {
+    let mut expectations = Vec::new();
+
+    for (day, inputs) in document {
+        let day = parse_day(&day)?;
+
+        for (input, parts) in inputs {
+            for (part, answer) in parts {
+                expectations.push(Expectation {
+                    day,
+                    input: input.clone(),
+                    part: parse_part(&part)?,
+                    answer: match answer {
+                        Value::Integer(value) => value.to_string(),
+                        Value::Text(value) => value,
+                    },
+                });
+            }
+        }
+    }
+
+    Ok(expectations)
+}
+
+pub fn parse(path: &Path, contents: &str) -> Result<Vec<Expectation>, String> {
+    let document: Document = match path.extension().and_then(|e| e.to_str()) {
+        Some("toml") => toml::from_str(contents).map_err(|e| e.to_string())?,
+        Some("json") => serde_json::from_str(contents).map_err(|e| e.to_string())?,
+        _ => return Err(String::from("Expected a .toml or .json answers file")),
+    };
+
+    expectations(document).map_err(|error| format!("{}: {}", path.display(), error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expectation(day: u8, input: &str, part: Part, answer: &str) -> Expectation {
+        Expectation {
+            day,
+            input: input.to_string(),
+            part,
+            answer: answer.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_toml() {
+        let contents = r#"
+            [day21."test.txt"]
+            part1 = 5
+            part2 = "mxmxvkd,sqjhc,fvjkl"
+
+            [day01."p1.txt"]
+            part2 = 143933922
+        "#;
+
+        assert_eq!(
+            parse(Path::new("answers.toml"), contents),
+            Ok(vec![
+                expectation(1, "p1.txt", Part::Two, "143933922"),
+                expectation(21, "test.txt", Part::One, "5"),
+                expectation(21, "test.txt", Part::Two, "mxmxvkd,sqjhc,fvjkl"),
+            ])
+        );
+    }
+
+    #[test]
+    fn parses_json() {
+        let contents = r#"{ "day25": { "p1.txt": { "part2": "Merry Christmas!" } } }"#;
+
+        assert_eq!(
+            parse(Path::new("answers.json"), contents),
+            Ok(vec![expectation(
+                25,
+                "p1.txt",
+                Part::Two,
+                "Merry Christmas!"
+            )])
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_keys() {
+        let parse_toml = |contents| parse(Path::new("answers.toml"), contents);
+
+        assert!(parse_toml("[day26.\"p1.txt\"]\npart1 = 1").is_err());
+        assert!(parse_toml("[day1x.\"p1.txt\"]\npart1 = 1").is_err());
+        assert!(parse_toml("[day01.\"p1.txt\"]\npart3 = 1").is_err());
+        assert!(parse(Path::new("answers.yaml"), "").is_err());
+    }
+}
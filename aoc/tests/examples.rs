@@ -0,0 +1,185 @@
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+fn example(day: u8, name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{:02}", day))
+        .join("input")
+        .join(name)
+}
+
+fn aoc(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .output()
+        .expect("Could not run aoc")
+}
+
+fn run_example(day: u8, name: &str, part: Option<u8>) -> Output {
+    let day_arg = day.to_string();
+    let input = example(day, name);
+    let mut args = vec!["run", &day_arg, "--input", input.to_str().unwrap()];
+    let part_arg = part.map(|part| part.to_string());
+
+    if let Some(part) = &part_arg {
+        args.extend(&["--part", part]);
+    }
+
+    aoc(&args)
+}
+
+fn assert_answers(day: u8, name: &str, part: Option<u8>, expected: &[&str]) {
+    let output = run_example(day, name, part);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let parts: Vec<u8> = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let expected: Vec<String> = parts
+        .iter()
+        .zip(expected)
+        .map(|(part, answer)| format!("Day {:02} part {}: {}", day, part, answer))
+        .collect();
+
+    assert!(
+        output.status.success(),
+        "day {} {} failed: {}",
+        day,
+        name,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(stdout.lines().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn examples() {
+    assert_answers(1, "test.txt", None, &["514579", "241861950"]);
+    assert_answers(2, "test.txt", None, &["2", "1"]);
+    assert_answers(3, "test.txt", None, &["7", "336"]);
+    assert_answers(4, "test.txt", None, &["2", "2"]);
+    assert_answers(4, "test2.txt", None, &["8", "4"]);
+    assert_answers(5, "test.txt", Some(1), &["820"]);
+    assert_answers(6, "test.txt", None, &["11", "6"]);
+    assert_answers(7, "test.txt", None, &["4", "32"]);
+    assert_answers(7, "test2.txt", Some(2), &["126"]);
+    assert_answers(8, "test.txt", None, &["5", "8"]);
+    assert_answers(10, "test.txt", None, &["35", "8"]);
+    assert_answers(10, "test2.txt", None, &["220", "19208"]);
+    assert_answers(11, "test.txt", None, &["37", "26"]);
+    assert_answers(12, "test.txt", None, &["25", "286"]);
+    assert_answers(13, "test.txt", None, &["295", "1068781"]);
+    assert_answers(14, "test.txt", Some(1), &["165"]);
+    assert_answers(14, "test2.txt", Some(2), &["208"]);
+    assert_answers(15, "test.txt", Some(1), &["436"]);
+    assert_answers(16, "test.txt", Some(1), &["71"]);
+    assert_answers(16, "test2.txt", Some(2), &["156"]);
+    assert_answers(17, "test.txt", None, &["112", "848"]);
+    assert_answers(18, "test.txt", None, &["26335", "693891"]);
+    assert_answers(19, "test2.txt", Some(1), &["2"]);
+    assert_answers(19, "test3.txt", None, &["3", "12"]);
+    assert_answers(20, "test.txt", None, &["20899048083289", "273"]);
+    assert_answers(21, "test.txt", None, &["5", "mxmxvkd,sqjhc,fvjkl"]);
+    assert_answers(22, "test.txt", None, &["306", "291"]);
+    assert_answers(23, "test.txt", Some(1), &["67384529"]);
+    assert_answers(24, "test.txt", None, &["10", "2208"]);
+    assert_answers(25, "test.txt", None, &["14897079", "Merry Christmas!"]);
+}
+
+#[test]
+fn failing_parts_exit_with_an_error() {
+    // The day 9 example uses a preamble of 5, so the real preamble of 25 finds nothing.
+    let output = run_example(9, "test.txt", Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr.contains("Nothing wrong with the input"));
+}
+
+#[test]
+fn invalid_arguments_print_usage() {
+    let output = aoc(&["run", "26"]);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr.contains("Usage:"));
+}
+
+#[test]
+fn missing_input_files_are_reported() {
+    let output = aoc(&["run", "1", "--input", "does/not/exist.txt"]);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr.contains("does/not/exist.txt"));
+}
+
+#[test]
+fn json_output_includes_answers_and_errors() {
+    let input = example(21, "test.txt");
+    let output = aoc(&["run", "21", "-i", input.to_str().unwrap(), "-f", "json"]);
+    let records: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(records[1]["answer"], "mxmxvkd,sqjhc,fvjkl");
+    assert_eq!(records[1]["error"], serde_json::Value::Null);
+    assert!(records[1]["elapsed"].is_f64());
+
+    let input = example(9, "test.txt");
+    let output = aoc(&["run", "9", "-i", input.to_str().unwrap(), "-f", "json"]);
+    let records: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(records[0]["answer"], serde_json::Value::Null);
+    assert_eq!(records[0]["error"], "Nothing wrong with the input");
+}
+
+#[test]
+fn json_output_includes_unreadable_inputs() {
+    let output = aoc(&["run", "1", "-i", "missing.txt", "-f", "json"]);
+    let records: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(records.as_array().unwrap().len(), 2);
+    assert_eq!(records[1]["part"], 2);
+    assert_eq!(records[1]["answer"], serde_json::Value::Null);
+    assert!(records[1]["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("Could not read missing.txt: "));
+}
+
+fn verify(name: &str, answers: &str) -> Output {
+    let path = std::env::temp_dir().join(name);
+    std::fs::write(&path, answers).unwrap();
+
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["verify", path.to_str().unwrap()])
+        .current_dir(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".."))
+        .output()
+        .expect("Could not run aoc")
+}
+
+#[test]
+fn verify_reports_passes_and_mismatches() {
+    let output = verify(
+        "aoc-verify-pass.toml",
+        "[day21.\"test.txt\"]\npart1 = 5\npart2 = \"mxmxvkd,sqjhc,fvjkl\"\n",
+    );
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(output.status.success());
+    assert!(stdout.contains("ok   Day 21 part 2 (test.txt)"));
+    assert!(stdout.contains("2 passed, 0 failed"));
+
+    let output = verify(
+        "aoc-verify-fail.json",
+        r#"{ "day01": { "test.txt": { "part1": 1, "part2": 241861950 } } }"#,
+    );
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout.contains("FAIL Day 01 part 1 (test.txt)\n    expected: 1\n    actual:   514579"));
+    assert!(stdout.contains("1 passed, 1 failed"));
+}
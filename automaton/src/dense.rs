@@ -0,0 +1,75 @@
+use crate::{Automaton, Rule};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// A fixed set of cells whose neighbourhoods are resolved once, up front.
+// Neighbours outside the set are treated as permanently dead.
+pub struct Dense<C> {
+    cells: Vec<C>,
+    index: HashMap<C, usize>,
+    alive: Vec<bool>,
+    neighbours: Vec<Vec<usize>>,
+    rule: Rule,
+}
+
+impl<C: Copy + Eq + Hash> Dense<C> {
+    pub fn new<I, F>(cells: I, neighbours: F, rule: Rule) -> Self
+    where
+        I: IntoIterator<Item = (C, bool)>,
+        F: Fn(&C) -> Vec<C>,
+    {
+        let (cells, alive): (Vec<C>, Vec<bool>) = cells.into_iter().unzip();
+        let index: HashMap<C, usize> = cells.iter().enumerate().map(|(i, &c)| (c, i)).collect();
+        let neighbours = cells
+            .iter()
+            .map(|cell| {
+                neighbours(cell)
+                    .iter()
+                    .filter_map(|neighbour| index.get(neighbour).copied())
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            cells,
+            index,
+            alive,
+            neighbours,
+            rule,
+        }
+    }
+
+    pub fn is_alive(&self, cell: &C) -> bool {
+        self.index.get(cell).is_some_and(|&i| self.alive[i])
+    }
+
+    pub fn alive(&self) -> impl Iterator<Item = C> + '_ {
+        self.cells
+            .iter()
+            .zip(&self.alive)
+            .filter(|(_, &alive)| alive)
+            .map(|(&cell, _)| cell)
+    }
+}
+
+impl<C> Automaton for Dense<C> {
+    fn step(&mut self) -> bool {
+        let next: Vec<bool> = self
+            .neighbours
+            .iter()
+            .zip(&self.alive)
+            .map(|(neighbours, &alive)| {
+                let count = neighbours.iter().filter(|&&i| self.alive[i]).count();
+                self.rule.next(alive, count)
+            })
+            .collect();
+
+        let changed = next != self.alive;
+        self.alive = next;
+        changed
+    }
+
+    fn population(&self) -> usize {
+        self.alive.iter().filter(|&&alive| alive).count()
+    }
+}
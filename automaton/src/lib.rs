@@ -0,0 +1,142 @@
+pub use dense::Dense;
+pub use sparse::Sparse;
+
+mod dense;
+mod sparse;
+pub mod topology;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    birth: Vec<usize>,
+    survival: Vec<usize>,
+}
+
+impl Rule {
+    // Neighbour counts that bring a dead cell to life, and those that keep a
+    // live cell alive, as in Life's B3/S23.
+    pub fn new(birth: &[usize], survival: &[usize]) -> Self {
+        Self {
+            birth: birth.to_vec(),
+            survival: survival.to_vec(),
+        }
+    }
+
+    pub fn next(&self, alive: bool, neighbours: usize) -> bool {
+        match alive {
+            true => self.survival.contains(&neighbours),
+            false => self.birth.contains(&neighbours),
+        }
+    }
+}
+
+pub trait Automaton {
+    // Advances one generation and reports whether any cell changed.
+    fn step(&mut self) -> bool;
+    fn population(&self) -> usize;
+
+    fn step_n(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    // Returns the number of generations it took, or `None` when the pattern
+    // is still changing after `max_generations`, as oscillators do forever.
+    fn step_until_stable(&mut self, max_generations: usize) -> Option<usize> {
+        (0..=max_generations).find(|_| !self.step())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::topology::{cube, hex, square};
+    use super::*;
+
+    fn life() -> Rule {
+        Rule::new(&[3], &[2, 3])
+    }
+
+    #[test]
+    fn applies_rules() {
+        assert!(life().next(false, 3));
+        assert!(!life().next(false, 2));
+        assert!(life().next(true, 2));
+        assert!(!life().next(true, 4));
+    }
+
+    #[test]
+    fn blinker_oscillates() {
+        let blinker = [(1, 0), (1, 1), (1, 2)];
+        let mut automaton = Sparse::new(blinker.iter().cloned(), square, life());
+
+        automaton.step();
+        assert!(automaton.is_alive(&(0, 1)) && automaton.is_alive(&(2, 1)));
+        assert!(!automaton.is_alive(&(1, 0)));
+
+        automaton.step();
+        assert_eq!(automaton.alive().count(), 3);
+        assert!(blinker.iter().all(|cell| automaton.is_alive(cell)));
+    }
+
+    #[test]
+    fn dense_and_sparse_agree() {
+        let glider = [(0, 1), (1, 2), (2, 0), (2, 1), (2, 2)];
+        let cells = (0..8).flat_map(|row| (0..8).map(move |column| (row, column)));
+        let mut dense = Dense::new(
+            cells.map(|cell| (cell, glider.contains(&cell))),
+            square,
+            life(),
+        );
+        let mut sparse = Sparse::new(glider.iter().cloned(), square, life());
+
+        dense.step_n(4);
+        sparse.step_n(4);
+
+        let mut dense_cells: Vec<_> = dense.alive().collect();
+        let mut sparse_cells: Vec<_> = sparse.alive().collect();
+        dense_cells.sort();
+        sparse_cells.sort();
+
+        assert_eq!(dense_cells, vec![(1, 2), (2, 3), (3, 1), (3, 2), (3, 3)]);
+        assert_eq!(dense_cells, sparse_cells);
+    }
+
+    #[test]
+    fn steps_until_stable() {
+        let block = [(0, 0), (0, 1), (1, 0), (1, 1)];
+        let mut automaton = Sparse::new(block.iter().cloned(), square, life());
+
+        assert_eq!(automaton.step_until_stable(10), Some(0));
+        assert_eq!(automaton.population(), 4);
+
+        let row = [((0, 0), true), ((0, 1), true), ((0, 2), true)];
+        let mut automaton = Dense::new(row.iter().cloned(), square, Rule::new(&[], &[1]));
+
+        assert_eq!(automaton.step_until_stable(2), Some(2));
+        assert_eq!(automaton.population(), 0);
+
+        let row = [((0, 0), true), ((0, 1), true), ((0, 2), true)];
+        let mut automaton = Dense::new(row.iter().cloned(), square, Rule::new(&[], &[1]));
+
+        assert_eq!(automaton.step_until_stable(1), None);
+    }
+
+    #[test]
+    fn oscillators_never_stabilise() {
+        let blinker = [(1, 0), (1, 1), (1, 2)];
+        let mut automaton = Sparse::new(blinker.iter().cloned(), square, life());
+
+        assert_eq!(automaton.step_until_stable(100), None);
+        assert_eq!(automaton.population(), 3);
+    }
+
+    #[test]
+    fn neighbourhoods() {
+        assert_eq!(square(&(0, 0)).len(), 8);
+        assert_eq!(cube(&[0, 0, 0]).len(), 26);
+        assert_eq!(cube(&[0, 0, 0, 0]).len(), 80);
+        assert!(!cube(&[1, 2, 3]).contains(&[1, 2, 3]));
+        assert_eq!(hex(&(0, 0)).len(), 6);
+        assert!(hex(&(2, -1)).contains(&(2, 0)));
+    }
+}
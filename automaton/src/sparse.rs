@@ -0,0 +1,66 @@
+use crate::{Automaton, Rule};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+// Only live cells are stored, so the space is unbounded. Dead cells are only
+// considered next to a live one, which rules out births on zero neighbours.
+pub struct Sparse<C, F> {
+    alive: HashSet<C>,
+    neighbours: F,
+    rule: Rule,
+}
+
+impl<C, F> Sparse<C, F>
+where
+    C: Copy + Eq + Hash,
+    F: Fn(&C) -> Vec<C>,
+{
+    pub fn new<I>(alive: I, neighbours: F, rule: Rule) -> Self
+    where
+        I: IntoIterator<Item = C>,
+    {
+        Self {
+            alive: alive.into_iter().collect(),
+            neighbours,
+            rule,
+        }
+    }
+
+    pub fn is_alive(&self, cell: &C) -> bool {
+        self.alive.contains(cell)
+    }
+
+    pub fn alive(&self) -> impl Iterator<Item = C> + '_ {
+        self.alive.iter().copied()
+    }
+}
+
+impl<C, F> Automaton for Sparse<C, F>
+where
+    C: Copy + Eq + Hash,
+    F: Fn(&C) -> Vec<C>,
+{
+    fn step(&mut self) -> bool {
+        let mut counts: HashMap<C, usize> = self.alive.iter().map(|&cell| (cell, 0)).collect();
+
+        for cell in &self.alive {
+            for neighbour in (self.neighbours)(cell) {
+                *counts.entry(neighbour).or_insert(0) += 1;
+            }
+        }
+
+        let next: HashSet<C> = counts
+            .into_iter()
+            .filter(|(cell, count)| self.rule.next(self.alive.contains(cell), *count))
+            .map(|(cell, _)| cell)
+            .collect();
+
+        let changed = next != self.alive;
+        self.alive = next;
+        changed
+    }
+
+    fn population(&self) -> usize {
+        self.alive.len()
+    }
+}
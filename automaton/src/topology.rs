@@ -0,0 +1,45 @@
+pub type Square = (isize, isize);
+pub type Hex = (i32, i32);
+
+pub fn square(&(row, column): &Square) -> Vec<Square> {
+    let mut neighbours = Vec::with_capacity(8);
+
+    for row_step in -1..=1 {
+        for column_step in -1..=1 {
+            if (row_step, column_step) != (0, 0) {
+                neighbours.push((row + row_step, column + column_step));
+            }
+        }
+    }
+
+    neighbours
+}
+
+// The 3^N - 1 cells that differ by at most one along every axis.
+pub fn cube<const N: usize>(cell: &[i32; N]) -> Vec<[i32; N]> {
+    let mut neighbours = vec![*cell];
+
+    for axis in 0..N {
+        neighbours = neighbours
+            .into_iter()
+            .flat_map(|neighbour| {
+                (-1..=1).map(move |step| {
+                    let mut neighbour = neighbour;
+                    neighbour[axis] += step;
+                    neighbour
+                })
+            })
+            .collect();
+    }
+
+    neighbours.retain(|neighbour| neighbour != cell);
+    neighbours
+}
+
+// Axial coordinates: `x` grows to the east and `y` to the north-east.
+pub fn hex(&(x, y): &Hex) -> Vec<Hex> {
+    [(0, 1), (-1, 1), (1, -1), (0, -1), (1, 0), (-1, 0)]
+        .iter()
+        .map(|(x_step, y_step)| (x + x_step, y + y_step))
+        .collect()
+}
@@ -0,0 +1,111 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    Parse,
+    InvalidArgument,
+    NoSolution,
+    Io,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub kind: ErrorKind,
+    pub message: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn new<S: Into<String>>(kind: ErrorKind, message: S) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            line: None,
+            column: None,
+        }
+    }
+
+    pub fn parse<S: Into<String>>(message: S) -> Self {
+        Self::new(ErrorKind::Parse, message)
+    }
+
+    pub fn invalid_argument<S: Into<String>>(message: S) -> Self {
+        Self::new(ErrorKind::InvalidArgument, message)
+    }
+
+    pub fn no_solution<S: Into<String>>(message: S) -> Self {
+        Self::new(ErrorKind::NoSolution, message)
+    }
+
+    pub fn io<S: Into<String>>(message: S) -> Self {
+        Self::new(ErrorKind::Io, message)
+    }
+
+    // Lines and columns are 1-based. Inner contexts win, so nested parsers can
+    // report a precise location that the outer loop won't overwrite.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    // Moves a line reported relative to a section of the input, such as a
+    // blank-line separated group, to its line in the whole input.
+    pub fn offset_line(mut self, offset: usize) -> Self {
+        self.line = self.line.map(|line| line + offset);
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (Some(line), None) => write!(f, "line {}: ", line)?,
+            _ => (),
+        }
+
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub trait Context<T> {
+    fn at_line(self, line: usize) -> Result<T>;
+    fn at_column(self, column: usize) -> Result<T>;
+    fn offset_line(self, offset: usize) -> Result<T>;
+}
+
+impl<T> Context<T> for Result<T> {
+    fn at_line(self, line: usize) -> Result<T> {
+        self.map_err(|error| error.at_line(line))
+    }
+
+    fn at_column(self, column: usize) -> Result<T> {
+        self.map_err(|error| error.at_column(column))
+    }
+
+    fn offset_line(self, offset: usize) -> Result<T> {
+        self.map_err(|error| error.offset_line(offset))
+    }
+}
+
+pub fn parse_value<T: FromStr>(value: &str) -> Result<T> {
+    value
+        .parse::<T>()
+        .map_err(|_| Error::parse(format!("Invalid value '{}'", value)))
+}
+
+// `fragment` must be a subslice of `line`, as returned by `split` and friends.
+pub fn column_of(line: &str, fragment: &str) -> usize {
+    fragment.as_ptr() as usize - line.as_ptr() as usize + 1
+}
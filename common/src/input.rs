@@ -0,0 +1,212 @@
+use crate::{Error, Result};
+use std::borrow::Cow;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    Path(PathBuf),
+    Default(u8),
+}
+
+impl Source {
+    pub fn read(&self) -> io::Result<String> {
+        let input = match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                input
+            }
+            Source::Path(path) => fs::read_to_string(path)?,
+            Source::Default(day) => fs::read_to_string(default_path(*day))?,
+        };
+
+        Ok(normalize(&input).into_owned())
+    }
+
+    // Like `read`, but with an error that names the source.
+    pub fn load(&self) -> Result<String> {
+        self.read()
+            .map_err(|error| Error::io(format!("Could not read {}: {}", self, error)))
+    }
+}
+
+// What subcommands that take their input on stdin read it with.
+pub fn stdin() -> Result<String> {
+    Source::Stdin.load()
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::Path(path) => write!(f, "{}", path.display()),
+            Source::Default(day) => write!(f, "{}", default_path(*day).display()),
+        }
+    }
+}
+
+pub fn default_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("day{:02}", day))
+        .join("input")
+        .join("p1.txt")
+}
+
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    match input.contains('\r') {
+        true => Cow::Owned(input.replace("\r\n", "\n")),
+        false => Cow::Borrowed(input),
+    }
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().map(str::trim_end)
+}
+
+fn split_line(input: &str) -> (&str, &str) {
+    match input.find('\n') {
+        Some(end) => (&input[..end], &input[end + 1..]),
+        None => (input, ""),
+    }
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+pub struct Groups<'a> {
+    rest: &'a str,
+    line: usize,
+}
+
+impl<'a> Iterator for Groups<'a> {
+    // The group and the 1-based line it starts on, so callers can report errors
+    // relative to the whole input.
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.rest.is_empty() {
+                return None;
+            }
+
+            let (line, rest) = split_line(self.rest);
+
+            if !is_blank(line) {
+                break;
+            }
+
+            self.rest = rest;
+            self.line += 1;
+        }
+
+        let start = self.rest;
+        let first_line = self.line;
+        let mut len = 0;
+
+        while !self.rest.is_empty() {
+            let (line, rest) = split_line(self.rest);
+
+            if is_blank(line) {
+                break;
+            }
+
+            len = line.as_ptr() as usize - start.as_ptr() as usize + line.len();
+            self.rest = rest;
+            self.line += 1;
+        }
+
+        Some((first_line, &start[..len]))
+    }
+}
+
+// Groups are separated by one or more blank lines; whitespace-only lines count
+// as blank and leading or trailing blank lines are ignored.
+pub fn groups(input: &str) -> Groups<'_> {
+    Groups {
+        rest: input,
+        line: 1,
+    }
+}
+
+// Items are subslices of `line`, so `column_of` still works on them.
+pub fn comma_list(line: &str) -> impl Iterator<Item = &str> {
+    line.trim().trim_end_matches(',').split(',').map(str::trim)
+}
+
+pub fn grid(input: &str) -> Result<Vec<Vec<char>>> {
+    let rows: Vec<Vec<char>> = lines(input.trim_end())
+        .map(|line| line.chars().collect())
+        .collect();
+
+    let columns = match rows.first() {
+        Some(row) if !row.is_empty() => row.len(),
+        _ => return Err(Error::parse("Empty grid")),
+    };
+
+    for (index, row) in rows.iter().enumerate() {
+        if row.len() != columns {
+            return Err(
+                Error::parse(format!("Expected {} columns, found {}", columns, row.len()))
+                    .at_line(index + 1),
+            );
+        }
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_line_endings() {
+        assert_eq!(normalize("a\r\nb\r\n"), "a\nb\n");
+        assert!(matches!(normalize("a\nb\n"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn names_unreadable_sources() {
+        let error = Source::Path(PathBuf::from("missing/p1.txt"))
+            .load()
+            .unwrap_err();
+
+        assert_eq!(error.kind, crate::ErrorKind::Io);
+        assert!(error.message.starts_with("Could not read missing/p1.txt: "));
+    }
+
+    #[test]
+    fn splits_groups() {
+        let input = "\na\nb\n\n\n  \nc\n\nd \n\n";
+
+        assert_eq!(
+            groups(input).collect::<Vec<_>>(),
+            vec![(2, "a\nb"), (7, "c"), (9, "d ")]
+        );
+        assert_eq!(groups("a\r\n\r\nb\r\n").count(), 2);
+        assert_eq!(groups("a\nb").collect::<Vec<_>>(), vec![(1, "a\nb")]);
+        assert_eq!(groups("\n \n").next(), None);
+    }
+
+    #[test]
+    fn splits_comma_lists() {
+        assert_eq!(
+            comma_list("0,3, 6,\n").collect::<Vec<_>>(),
+            vec!["0", "3", "6"]
+        );
+    }
+
+    #[test]
+    fn reads_grids() {
+        assert_eq!(
+            grid("#.\n.#\n\n").unwrap(),
+            vec![vec!['#', '.'], vec!['.', '#']]
+        );
+        assert_eq!(grid("#.\n.\n").unwrap_err().line, Some(2));
+        assert!(grid("\n").is_err());
+    }
+}
@@ -0,0 +1,113 @@
+use input::Source;
+use std::env;
+use std::fmt::Display;
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+use std::process;
+use std::time::{Duration, Instant};
+
+pub use error::{column_of, parse_value, Context, Error, ErrorKind, Result};
+pub use timing::{bench, Bencher, Timings};
+
+mod error;
+pub mod input;
+mod timing;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+pub trait Solution {
+    const DAY: u8;
+
+    type Input<'a>;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1>;
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2>;
+}
+
+#[derive(Debug, Clone)]
+pub struct Answer {
+    pub day: u8,
+    pub part: Part,
+    pub value: Result<String>,
+    // Time spent in the part itself, or in parsing when the input is invalid.
+    pub elapsed: Duration,
+}
+
+pub type Runner = fn(&str, &[Part]) -> Vec<Answer>;
+
+pub fn run<S: Solution>(input: &str, parts: &[Part]) -> Vec<Answer> {
+    let start = Instant::now();
+    let parsed = S::parse(input);
+    let parse_elapsed = start.elapsed();
+
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let value = match (&parsed, part) {
+                (Err(error), _) => Err(error.clone()),
+                (Ok(parsed), Part::One) => S::part1(parsed).map(|v| v.to_string()),
+                (Ok(parsed), Part::Two) => S::part2(parsed).map(|v| v.to_string()),
+            };
+            let elapsed = match parsed {
+                Ok(_) => start.elapsed(),
+                Err(_) => parse_elapsed,
+            };
+
+            Answer {
+                day: S::DAY,
+                part,
+                value,
+                elapsed,
+            }
+        })
+        .collect()
+}
+
+pub fn main<S: Solution>() {
+    // An explicit path wins, then piped input, then the day's default input file.
+    let source = match env::args_os().nth(1) {
+        Some(path) => Source::Path(PathBuf::from(path)),
+        None if io::stdin().is_terminal() => Source::Default(S::DAY),
+        None => Source::Stdin,
+    };
+
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{}: {}", source, error);
+            process::exit(1);
+        }
+    };
+    let mut failed = false;
+
+    for answer in run::<S>(&input, &[Part::One, Part::Two]) {
+        match answer.value {
+            Ok(value) => println!("{}", value),
+            Err(error) => {
+                eprintln!("Part {}: {}", answer.part.number(), error);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{Result, Solution};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub day: u8,
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+pub type Bencher = fn(&str, usize) -> Result<Timings>;
+
+fn median<F>(samples: usize, mut routine: F) -> Result<Duration>
+where
+    F: FnMut() -> Result<()>,
+{
+    let mut durations = Vec::with_capacity(samples);
+
+    for _ in 0..samples.max(1) {
+        let start = Instant::now();
+        routine()?;
+        durations.push(start.elapsed());
+    }
+
+    durations.sort();
+    Ok(durations[durations.len() / 2])
+}
+
+pub fn bench<S: Solution>(input: &str, samples: usize) -> Result<Timings> {
+    let parse = median(samples, || S::parse(input).map(drop))?;
+    let parsed = S::parse(input)?;
+
+    Ok(Timings {
+        day: S::DAY,
+        parse,
+        part1: median(samples, || S::part1(&parsed).map(drop))?,
+        part2: median(samples, || S::part2(&parsed).map(drop))?,
+    })
+}
@@ -0,0 +1,289 @@
+use common::{parse_value, Error};
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, BufRead};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry {
+    pub line: usize,
+    pub value: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Finding {
+    Pair([Entry; 2]),
+    Triple([Entry; 3]),
+    Duplicate { entry: Entry, first_line: usize },
+    Invalid(Error),
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let entries = |entries: &[Entry]| {
+            entries
+                .iter()
+                .map(|entry| format!("{} (line {})", entry.value, entry.line))
+                .collect::<Vec<_>>()
+                .join(" + ")
+        };
+
+        match self {
+            Finding::Pair(pair) => write!(f, "pair: {}", entries(pair)),
+            Finding::Triple(triple) => write!(f, "triple: {}", entries(triple)),
+            Finding::Duplicate { entry, first_line } => write!(
+                f,
+                "line {}: duplicate of line {} ({})",
+                entry.line, first_line, entry.value
+            ),
+            Finding::Invalid(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+// How many duplicates and invalid lines a report keeps as examples. The
+// `on_finding` callback of `audit` sees all of them.
+pub const EXAMPLES: usize = 10;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    pub entries: usize,
+    pub pair: Option<[Entry; 2]>,
+    pub triple: Option<[Entry; 3]>,
+    pub duplicates: usize,
+    pub duplicate_examples: Vec<(Entry, usize)>,
+    pub invalid: usize,
+    pub invalid_examples: Vec<Error>,
+}
+
+// Keeps one line per distinct value, plus a second one so a value can pair
+// with itself, and only the first few duplicates and invalid lines, which
+// bounds memory by the number of distinct values.
+pub struct Auditor {
+    target: i64,
+    line: usize,
+    seen: HashMap<i64, (usize, Option<usize>)>,
+    report: Report,
+}
+
+impl Auditor {
+    pub fn new(target: i64) -> Self {
+        Self {
+            target,
+            line: 0,
+            seen: HashMap::new(),
+            report: Report::default(),
+        }
+    }
+
+    fn find_pair(&self, entry: Entry) -> Option<[Entry; 2]> {
+        let value = self.target - entry.value;
+        let &(line, _) = self.seen.get(&value)?;
+
+        Some([Entry { line, value }, entry])
+    }
+
+    fn find_triple(&self, entry: Entry) -> Option<[Entry; 3]> {
+        let rest = self.target - entry.value;
+
+        self.seen.iter().find_map(|(&value, &(line, second))| {
+            let other = rest - value;
+            let other_line = match other == value {
+                true => second?,
+                false if other > value => self.seen.get(&other)?.0,
+                false => return None,
+            };
+
+            let mut triple = [
+                Entry { line, value },
+                Entry {
+                    line: other_line,
+                    value: other,
+                },
+                entry,
+            ];
+            triple.sort_by_key(|entry| entry.line);
+            Some(triple)
+        })
+    }
+
+    fn invalid(&mut self, error: Error) -> Vec<Finding> {
+        let error = error.at_line(self.line);
+
+        self.report.invalid += 1;
+
+        if self.report.invalid_examples.len() < EXAMPLES {
+            self.report.invalid_examples.push(error.clone());
+        }
+
+        vec![Finding::Invalid(error)]
+    }
+
+    // Lines that aren't UTF-8 are reported as invalid instead of failing.
+    pub fn feed_bytes(&mut self, line: &[u8]) -> Vec<Finding> {
+        match std::str::from_utf8(line) {
+            Ok(line) => self.feed(line),
+            Err(_) => {
+                self.line += 1;
+                self.invalid(Error::parse("Invalid UTF-8"))
+            }
+        }
+    }
+
+    // Returns what this line revealed, so callers can report it right away.
+    pub fn feed(&mut self, line: &str) -> Vec<Finding> {
+        self.line += 1;
+
+        let line = line.trim();
+        let mut findings = Vec::new();
+
+        if line.is_empty() {
+            return findings;
+        }
+
+        let entry = match parse_value::<i64>(line) {
+            Ok(value) => Entry {
+                line: self.line,
+                value,
+            },
+            Err(error) => return self.invalid(error),
+        };
+
+        self.report.entries += 1;
+
+        if self.report.pair.is_none() {
+            if let Some(pair) = self.find_pair(entry) {
+                self.report.pair = Some(pair);
+                findings.push(Finding::Pair(pair));
+            }
+        }
+
+        if self.report.triple.is_none() {
+            if let Some(triple) = self.find_triple(entry) {
+                self.report.triple = Some(triple);
+                findings.push(Finding::Triple(triple));
+            }
+        }
+
+        match self.seen.get_mut(&entry.value) {
+            Some((first_line, second)) => {
+                second.get_or_insert(entry.line);
+                self.report.duplicates += 1;
+
+                if self.report.duplicate_examples.len() < EXAMPLES {
+                    self.report.duplicate_examples.push((entry, *first_line));
+                }
+
+                findings.push(Finding::Duplicate {
+                    entry,
+                    first_line: *first_line,
+                });
+            }
+            None => {
+                self.seen.insert(entry.value, (entry.line, None));
+            }
+        }
+
+        findings
+    }
+
+    pub fn report(self) -> Report {
+        self.report
+    }
+}
+
+pub fn audit<R, F>(mut reader: R, target: i64, mut on_finding: F) -> io::Result<Report>
+where
+    R: BufRead,
+    F: FnMut(&Finding),
+{
+    let mut auditor = Auditor::new(target);
+    let mut line = Vec::new();
+
+    while reader.read_until(b'\n', &mut line)? > 0 {
+        auditor.feed_bytes(&line).iter().for_each(&mut on_finding);
+        line.clear();
+    }
+
+    Ok(auditor.report())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(line: usize, value: i64) -> Entry {
+        Entry { line, value }
+    }
+
+    #[test]
+    fn reports_as_soon_as_the_target_is_hit() {
+        let mut auditor = Auditor::new(2020);
+
+        assert_eq!(auditor.feed("1721"), vec![]);
+        assert_eq!(auditor.feed("979"), vec![]);
+        assert_eq!(auditor.feed("366"), vec![]);
+        assert_eq!(
+            auditor.feed("299"),
+            vec![Finding::Pair([entry(1, 1721), entry(4, 299)])]
+        );
+        assert_eq!(
+            auditor.feed("675"),
+            vec![Finding::Triple([
+                entry(2, 979),
+                entry(3, 366),
+                entry(5, 675)
+            ])]
+        );
+    }
+
+    #[test]
+    fn reports_duplicates_and_invalid_lines() {
+        let input = "1010\r\nabc\n\n5\n1010\n5\n";
+        let mut findings = Vec::new();
+        let report = audit(input.as_bytes(), 2020, |finding| {
+            findings.push(finding.to_string())
+        })
+        .unwrap();
+
+        assert_eq!(
+            findings,
+            vec![
+                "line 2: Invalid value 'abc'",
+                "pair: 1010 (line 1) + 1010 (line 5)",
+                "line 5: duplicate of line 1 (1010)",
+                "line 6: duplicate of line 4 (5)",
+            ]
+        );
+        assert_eq!(report.entries, 4);
+        assert_eq!(report.triple, None);
+        assert_eq!(report.invalid_examples[0].line, Some(2));
+    }
+
+    #[test]
+    fn keeps_counts_and_a_few_examples() {
+        let mut input = b"1\n\xff\xfe\n".to_vec();
+        input.extend("1\n".repeat(EXAMPLES + 5).bytes());
+        let mut findings = Vec::new();
+        let report = audit(input.as_slice(), 2020, |finding| {
+            findings.push(finding.to_string())
+        })
+        .unwrap();
+
+        assert_eq!(findings[0], "line 2: Invalid UTF-8");
+        assert_eq!(findings.len(), EXAMPLES + 6);
+        assert_eq!((report.entries, report.invalid), (EXAMPLES + 6, 1));
+        assert_eq!(report.duplicates, EXAMPLES + 5);
+        assert_eq!(report.duplicate_examples.len(), EXAMPLES);
+        assert_eq!(report.duplicate_examples[0], (entry(3, 1), 1));
+    }
+
+    #[test]
+    fn triples_can_reuse_a_duplicated_value() {
+        let report = audit("10\n1000\n1000\n".as_bytes(), 2010, |_| ()).unwrap();
+
+        assert_eq!(
+            report.triple,
+            Some([entry(1, 10), entry(2, 1000), entry(3, 1000)])
+        );
+    }
+}
@@ -0,0 +1,298 @@
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Combination {
+    pub indices: Vec<usize>,
+    pub values: Vec<i64>,
+}
+
+impl Combination {
+    fn new(numbers: &[i64], mut indices: Vec<usize>) -> Self {
+        indices.sort_unstable();
+
+        Self {
+            values: indices.iter().map(|&i| numbers[i]).collect(),
+            indices,
+        }
+    }
+
+    pub fn product(&self) -> i64 {
+        self.values.iter().product()
+    }
+}
+
+// All strategies call `visit` once per set of indices and stop as soon as it
+// returns false, which they report by returning false themselves.
+
+fn hashing<F>(numbers: &[i64], k: usize, target: i64, visit: &mut F) -> bool
+where
+    F: FnMut(Vec<usize>) -> bool,
+{
+    match k {
+        0 => target != 0 || visit(vec![]),
+        1 => (0..numbers.len())
+            .filter(|&i| numbers[i] == target)
+            .all(|i| visit(vec![i])),
+        _ => {
+            let mut seen: HashMap<i64, Vec<usize>> = HashMap::new();
+
+            for (j, &number) in numbers.iter().enumerate() {
+                for &i in seen.get(&(target - number)).into_iter().flatten() {
+                    if !visit(vec![i, j]) {
+                        return false;
+                    }
+                }
+
+                seen.entry(number).or_default().push(j);
+            }
+
+            true
+        }
+    }
+}
+
+fn two_pointer<F>(numbers: &[i64], target: i64, visit: &mut F) -> bool
+where
+    F: FnMut(Vec<usize>) -> bool,
+{
+    let mut order: Vec<usize> = (0..numbers.len()).collect();
+    order.sort_by_key(|&i| numbers[i]);
+    let value = |position: usize| numbers[order[position]];
+
+    for first in 0..order.len() {
+        let rest = target - value(first);
+        let (mut low, mut high) = (first + 1, order.len() - 1);
+
+        while low < high {
+            let sum = value(low) + value(high);
+
+            if sum < rest {
+                low += 1;
+            } else if sum > rest {
+                high -= 1;
+            } else if value(low) == value(high) {
+                // Every pair left in the window is a match.
+                for a in low..high {
+                    for b in a + 1..=high {
+                        if !visit(vec![order[first], order[a], order[b]]) {
+                            return false;
+                        }
+                    }
+                }
+
+                break;
+            } else {
+                let low_end = (low..high)
+                    .take_while(|&p| value(p) == value(low))
+                    .last()
+                    .unwrap();
+                let high_start = (low..=high)
+                    .rev()
+                    .take_while(|&p| value(p) == value(high))
+                    .last()
+                    .unwrap();
+
+                for a in low..=low_end {
+                    for b in high_start..=high {
+                        if !visit(vec![order[first], order[a], order[b]]) {
+                            return false;
+                        }
+                    }
+                }
+
+                low = low_end + 1;
+                high = high_start - 1;
+            }
+        }
+    }
+
+    true
+}
+
+// Index combinations of size `size` out of `0..n`, in lexicographic order.
+fn combinations<F>(n: usize, size: usize, mut visit: F) -> bool
+where
+    F: FnMut(&[usize]) -> bool,
+{
+    if size > n {
+        return true;
+    }
+
+    let mut combination: Vec<usize> = (0..size).collect();
+
+    loop {
+        if !visit(&combination) {
+            return false;
+        }
+
+        let mut position = size;
+
+        loop {
+            if position == 0 {
+                return true;
+            }
+
+            position -= 1;
+
+            if combination[position] < n - size + position {
+                break;
+            }
+        }
+
+        combination[position] += 1;
+
+        for next in position + 1..size {
+            combination[next] = combination[next - 1] + 1;
+        }
+    }
+}
+
+// Splits each combination into its lower and upper halves by index, so every
+// combination is found exactly once.
+fn meet_in_the_middle<F>(numbers: &[i64], k: usize, target: i64, visit: &mut F) -> bool
+where
+    F: FnMut(Vec<usize>) -> bool,
+{
+    let sum = |combination: &[usize]| combination.iter().map(|&i| numbers[i]).sum::<i64>();
+    let mut lower: HashMap<i64, Vec<Vec<usize>>> = HashMap::new();
+
+    combinations(numbers.len(), k / 2, |combination| {
+        lower
+            .entry(sum(combination))
+            .or_default()
+            .push(combination.to_vec());
+        true
+    });
+
+    combinations(numbers.len(), k - k / 2, |upper| {
+        lower
+            .get(&(target - sum(upper)))
+            .into_iter()
+            .flatten()
+            .filter(|lower| lower.last() < Some(&upper[0]))
+            .all(|lower| visit([lower.as_slice(), upper].concat()))
+    })
+}
+
+fn search<F>(numbers: &[i64], k: usize, target: i64, mut visit: F)
+where
+    F: FnMut(Vec<usize>) -> bool,
+{
+    match k {
+        0..=2 => hashing(numbers, k, target, &mut visit),
+        3 => two_pointer(numbers, target, &mut visit),
+        _ => meet_in_the_middle(numbers, k, target, &mut visit),
+    };
+}
+
+pub fn find_k_sum(numbers: &[i64], k: usize, target: i64) -> Option<Combination> {
+    let mut found = None;
+
+    search(numbers, k, target, |indices| {
+        found = Some(indices);
+        false
+    });
+
+    found.map(|indices| Combination::new(numbers, indices))
+}
+
+// Calls `visit` with every set of `k` entries summing to `target`, until it
+// returns false. Solutions are distinct sets of indices, so equal values count
+// once per position: `[5, 5, 5]` has three pairs summing to 10.
+pub fn k_sums<F>(numbers: &[i64], k: usize, target: i64, mut visit: F)
+where
+    F: FnMut(Combination) -> bool,
+{
+    search(numbers, k, target, |indices| {
+        visit(Combination::new(numbers, indices))
+    });
+}
+
+pub fn count_k_sums(numbers: &[i64], k: usize, target: i64) -> usize {
+    let mut count = 0;
+
+    search(numbers, k, target, |_| {
+        count += 1;
+        true
+    });
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NUMBERS: [i64; 10] = [5, -2, 7, 5, 0, 3, 5, 12, -2, 8];
+
+    fn brute_force(numbers: &[i64], k: usize, target: i64, start: usize) -> usize {
+        match k {
+            0 => (target == 0) as usize,
+            _ => (start..numbers.len())
+                .map(|i| brute_force(numbers, k - 1, target - numbers[i], i + 1))
+                .sum(),
+        }
+    }
+
+    #[test]
+    fn counts_match_brute_force() {
+        for k in 0..=6 {
+            for target in -4..=30 {
+                assert_eq!(
+                    count_k_sums(&NUMBERS, k, target),
+                    brute_force(&NUMBERS, k, target, 0),
+                    "k = {}, target = {}",
+                    k,
+                    target
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn finds_values_and_indices() {
+        let combination = find_k_sum(&[1721, 979, 366, 299, 675, 1456], 3, 2020).unwrap();
+
+        assert_eq!(combination.indices, vec![1, 2, 4]);
+        assert_eq!(combination.values, vec![979, 366, 675]);
+        assert_eq!(combination.product(), 241861950);
+        assert_eq!(find_k_sum(&[1, 2], 2, 2020), None);
+        assert_eq!(find_k_sum(&[2020], 2, 2020), None);
+    }
+
+    #[test]
+    fn enumerates_distinct_solutions() {
+        let mut pairs: Vec<Vec<usize>> = Vec::new();
+        k_sums(&NUMBERS, 2, 10, |combination| {
+            pairs.push(combination.indices);
+            true
+        });
+
+        assert_eq!(pairs.len(), 6);
+        assert!(pairs.contains(&vec![0, 3]));
+        assert!(pairs.contains(&vec![1, 7]));
+        assert!(pairs.iter().all(|pair| pair[0] < pair[1]));
+
+        let mut fives = Vec::new();
+        k_sums(&[5, 5, 5], 2, 10, |combination| {
+            fives.push(combination);
+            true
+        });
+
+        assert_eq!(fives.len(), 3);
+        assert!(fives.iter().all(|pair| pair.values == vec![5, 5]));
+    }
+
+    #[test]
+    fn stops_when_asked() {
+        for k in 2..=4 {
+            let mut visited = 0;
+            k_sums(&NUMBERS, k, 10, |_| {
+                visited += 1;
+                visited < 2
+            });
+
+            assert_eq!(visited, count_k_sums(&NUMBERS, k, 10).min(2), "k = {}", k);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use common::{parse_value, Context, Error, Result, Solution};
+pub use ksum::{count_k_sums, find_k_sum, k_sums, Combination};
+
+pub mod audit;
+pub mod ksum;
+
+pub const TARGET: i64 = 2020;
+
+pub fn parse_ints(input: &str) -> Result<Vec<i64>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_value::<i64>(line).at_line(index + 1))
+        .collect()
+}
+
+pub fn part1(numbers: &[i64]) -> Result<i64> {
+    match find_k_sum(numbers, 2, TARGET) {
+        Some(pair) => Ok(pair.product()),
+        None => Err(Error::no_solution("No pair sums to 2020")),
+    }
+}
+
+pub fn part2(numbers: &[i64]) -> Result<i64> {
+    match find_k_sum(numbers, 3, TARGET) {
+        Some(triple) => Ok(triple.product()),
+        None => Err(Error::no_solution("No triple sums to 2020")),
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_ints(input)
+    }
+
+    fn part1(values: &Self::Input<'_>) -> Result<Self::Part1> {
+        part1(values)
+    }
+
+    fn part2(values: &Self::Input<'_>) -> Result<Self::Part2> {
+        part2(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::ErrorKind;
+
+    const EXAMPLE: &str = include_str!("../input/test.txt");
+
+    #[test]
+    fn example() {
+        let numbers = parse_ints(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&numbers), Ok(514579));
+        assert_eq!(part2(&numbers), Ok(241861950));
+    }
+
+    #[test]
+    fn reports_invalid_lines() {
+        let error = parse_ints("1721\nabc\n").unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::Parse);
+        assert_eq!(error.line, Some(2));
+    }
+
+    #[test]
+    fn reports_missing_solution() {
+        assert_eq!(part1(&[1, 2, 3]).unwrap_err().kind, ErrorKind::NoSolution);
+        assert_eq!(part1(&[1010]).unwrap_err().kind, ErrorKind::NoSolution);
+        assert_eq!(part2(&[]).unwrap_err().kind, ErrorKind::NoSolution);
+    }
+}
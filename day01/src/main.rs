@@ -1,57 +1,37 @@
-use std::collections::HashMap;
-use std::io::{self, Read};
-
-pub fn parse_ints(input: &str) -> Vec<i64> {
-    input
-        .lines()
-        .map(|line| line.parse::<i64>().unwrap())
-        .collect()
-}
-
-pub fn part1(numbers: &Vec<i64>) -> i64 {
-    let mut start = 0;
-    let mut end = numbers.len() - 1;
-
-    loop {
-        let sum = numbers[start] + numbers[end];
-
-        if sum > 2020 {
-            end -= 1;
-        } else if sum < 2020 {
-            start += 1;
-        } else {
-            break;
-        }
-    }
-
-    numbers[start] * numbers[end]
+use day01::audit::audit;
+use day01::{Day01, TARGET};
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::process;
+
+// `day01 audit [path]` streams the report instead of loading it into memory.
+fn run_audit(path: Option<String>) -> io::Result<()> {
+    let reader: Box<dyn BufRead> = match &path {
+        Some(path) => Box::new(BufReader::new(File::open(path)?)),
+        None => Box::new(io::stdin().lock()),
+    };
+
+    let report = audit(reader, TARGET, |finding| println!("{}", finding))?;
+
+    println!(
+        "{} entries, {} duplicates, {} invalid lines",
+        report.entries, report.duplicates, report.invalid
+    );
+
+    Ok(())
 }
 
-pub fn part2(numbers: &Vec<i64>) -> i64 {
-    let len = numbers.len();
-
-    for i in 0..len - 1 {
-        let mut cache: HashMap<i64, bool> = HashMap::new();
-
-        for j in i + 1..len {
-            let looking_for = 2020 - numbers[i] - numbers[j];
+fn main() {
+    let mut args = env::args().skip(1);
 
-            if cache.contains_key(&looking_for) {
-                return numbers[i] * numbers[j] * looking_for;
+    match args.next().as_deref() {
+        Some("audit") => {
+            if let Err(error) = run_audit(args.next()) {
+                eprintln!("{}", error);
+                process::exit(1);
             }
-
-            cache.insert(numbers[j], true);
         }
+        _ => common::main::<Day01>(),
     }
-
-    return 0;
-}
-
-fn main() {
-    let mut input = String::new();
-    io::stdin().read_to_string(&mut input).unwrap();
-    let mut values = parse_ints(&input);
-    values.sort();
-    println!("{:?}", part1(&values));
-    println!("{:?}", part2(&values));
 }
@@ -0,0 +1,249 @@
+use common::{parse_value, Context, Error, Result, Solution};
+use unicode_segmentation::UnicodeSegmentation;
+
+pub use policy::{audit, policy, PasswordPolicy, Violation};
+
+pub mod policy;
+
+// What the policy's positions and its character refer to. Bytes are only
+// meaningful for ASCII passwords; `Auto` picks bytes for those and grapheme
+// clusters for everything else.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Positions {
+    Auto,
+    Bytes,
+    Chars,
+    Graphemes,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Policy<'s> {
+    c1: u32,
+    c2: u32,
+    character: &'s str,
+    input: &'s str,
+}
+
+fn expect(line: &str, position: usize, expected: u8) -> Result<()> {
+    match line.as_bytes().get(position) {
+        Some(&byte) if byte == expected => Ok(()),
+        _ => Err(Error::parse(format!("Expected '{}'", expected as char)).at_column(position + 1)),
+    }
+}
+
+fn number(line: &str, start: usize) -> Result<(u32, usize)> {
+    let digits = line.as_bytes()[start..]
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    let end = start + digits;
+
+    Ok((parse_value(&line[start..end]).at_column(start + 1)?, end))
+}
+
+impl<'s> Policy<'s> {
+    // Walks the bytes of `<c1>-<c2> <char>: <password>` without allocating.
+    pub fn parse(line: &'s str) -> Result<Self> {
+        let (c1, end) = number(line, 0)?;
+        expect(line, end, b'-')?;
+        let (c2, end) = number(line, end + 1)?;
+        expect(line, end, b' ')?;
+
+        let start = end + 1;
+        let end = match line[start..].find(": ") {
+            Some(length) => start + length,
+            None => return Err(Error::parse("Expected ': '").at_column(start + 1)),
+        };
+        let character = &line[start..end];
+
+        if character.graphemes(true).count() != 1 {
+            return Err(
+                Error::parse(format!("Invalid character '{}'", character)).at_column(start + 1)
+            );
+        }
+
+        let input = line[end + 2..].trim_end();
+
+        if input.is_empty() || input.contains(char::is_whitespace) {
+            return Err(Error::parse(format!("Invalid password '{}'", input)).at_column(end + 3));
+        }
+
+        Ok(Self {
+            c1,
+            c2,
+            character,
+            input,
+        })
+    }
+
+    pub fn password(&self) -> &'s str {
+        self.input
+    }
+
+    pub fn character(&self) -> &'s str {
+        self.character
+    }
+
+    // The two numbers before the character, whose meaning depends on the policy.
+    pub fn bounds(&self) -> (u32, u32) {
+        (self.c1, self.c2)
+    }
+
+    fn resolve(&self, positions: Positions) -> Positions {
+        match positions {
+            Positions::Auto if self.input.is_ascii() && self.character.is_ascii() => {
+                Positions::Bytes
+            }
+            Positions::Auto => Positions::Graphemes,
+            _ => positions,
+        }
+    }
+
+    pub fn count(&self, positions: Positions) -> usize {
+        match self.resolve(positions) {
+            Positions::Graphemes => self
+                .input
+                .graphemes(true)
+                .filter(|&grapheme| grapheme == self.character)
+                .count(),
+            _ => self.input.matches(self.character).count(),
+        }
+    }
+
+    // Whether the character sits at the 1-based `position`.
+    pub fn at(&self, position: u32, positions: Positions) -> bool {
+        let index = match (position as usize).checked_sub(1) {
+            Some(index) => index,
+            None => return false,
+        };
+
+        match self.resolve(positions) {
+            Positions::Graphemes => self.input.graphemes(true).nth(index) == Some(self.character),
+            Positions::Chars => self
+                .input
+                .char_indices()
+                .nth(index)
+                .is_some_and(|(offset, _)| self.input[offset..].starts_with(self.character)),
+            _ => {
+                self.input
+                    .as_bytes()
+                    .get(index..index + self.character.len())
+                    == Some(self.character.as_bytes())
+            }
+        }
+    }
+
+    pub fn count_comply(&self, positions: Positions) -> bool {
+        (self.c1 as usize..=self.c2 as usize).contains(&self.count(positions))
+    }
+
+    pub fn position_comply(&self, positions: Positions) -> bool {
+        self.at(self.c1, positions) != self.at(self.c2, positions)
+    }
+
+    pub fn old_policy_comply(&self) -> bool {
+        self.count_comply(Positions::Auto)
+    }
+
+    pub fn new_policy_comply(&self) -> bool {
+        self.position_comply(Positions::Auto)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Policy<'_>>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| Policy::parse(line).at_line(index + 1))
+        .collect()
+}
+
+pub fn part1(policies: &[Policy]) -> usize {
+    policies
+        .iter()
+        .filter(|policy| policy.old_policy_comply())
+        .count()
+}
+
+pub fn part2(policies: &[Policy]) -> usize {
+    policies
+        .iter()
+        .filter(|policy| policy.new_policy_comply())
+        .count()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Policy<'a>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(policies: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(part1(policies))
+    }
+
+    fn part2(policies: &Self::Input<'_>) -> Result<Self::Part2> {
+        Ok(part2(policies))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.txt");
+
+    #[test]
+    fn example() {
+        let policies = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&policies), 2);
+        assert_eq!(part2(&policies), 1);
+    }
+
+    #[test]
+    fn new_policy_ignores_positions_past_the_end() {
+        assert!(!Policy::parse("1-9 a: b").unwrap().new_policy_comply());
+        assert!(Policy::parse("1-9 a: a").unwrap().new_policy_comply());
+    }
+
+    #[test]
+    fn reports_invalid_policies() {
+        let error = parse("1-3 a: abcde\n1-x b: cdefg\n").unwrap_err();
+
+        assert_eq!(error.line, Some(2));
+        assert_eq!(error.column, Some(3));
+
+        assert_eq!(parse("1-3 a abcde").unwrap_err().column, Some(5));
+        assert_eq!(parse("1-3 ab: abcde").unwrap_err().column, Some(5));
+        assert_eq!(parse("1-3 a: ").unwrap_err().column, Some(8));
+    }
+
+    #[test]
+    fn positions_are_selectable() {
+        // "e" followed by a combining acute accent is one grapheme but two chars.
+        let policy = Policy::parse("1-2 e: e\u{301}e").unwrap();
+
+        assert!(policy.at(1, Positions::Bytes));
+        assert!(policy.at(1, Positions::Chars));
+        assert!(!policy.at(1, Positions::Graphemes));
+        assert!(policy.at(2, Positions::Graphemes));
+        assert!(policy.at(4, Positions::Bytes));
+        assert!(policy.at(3, Positions::Chars));
+        assert_eq!(policy.count(Positions::Chars), 2);
+        assert_eq!(policy.count(Positions::Graphemes), 1);
+        assert!(policy.new_policy_comply());
+
+        let policy = Policy::parse("1-1 🇫🇷: 🇫🇷x").unwrap();
+
+        assert!(policy.at(1, Positions::Auto));
+        assert!(!policy.at(2, Positions::Auto));
+    }
+}
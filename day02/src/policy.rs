@@ -0,0 +1,364 @@
+use crate::{Policy, Positions};
+use common::{parse_value, Error, Result};
+use std::fmt;
+
+pub trait PasswordPolicy {
+    fn name(&self) -> String;
+
+    // Explains why the entry breaks the policy, or returns None if it complies.
+    fn violation(&self, entry: &Policy) -> Option<String>;
+}
+
+fn positions_name(name: &str, positions: Positions) -> String {
+    match positions {
+        Positions::Auto => name.to_string(),
+        Positions::Bytes => format!("{}:bytes", name),
+        Positions::Chars => format!("{}:chars", name),
+        Positions::Graphemes => format!("{}:graphemes", name),
+    }
+}
+
+// The sled rental shop's rule: the character must appear between c1 and c2 times.
+pub struct SledRental(pub Positions);
+
+impl PasswordPolicy for SledRental {
+    fn name(&self) -> String {
+        positions_name("sled", self.0)
+    }
+
+    fn violation(&self, entry: &Policy) -> Option<String> {
+        if entry.count_comply(self.0) {
+            return None;
+        }
+
+        let count = entry.count(self.0);
+        let (min, max) = entry.bounds();
+
+        Some(format!(
+            "'{}' appears {} times, expected {}-{}",
+            entry.character(),
+            count,
+            min,
+            max
+        ))
+    }
+}
+
+// The Official Toboggan Corporate Policy: the character must be at exactly one
+// of the 1-based positions c1 and c2.
+pub struct Toboggan(pub Positions);
+
+impl PasswordPolicy for Toboggan {
+    fn name(&self) -> String {
+        positions_name("toboggan", self.0)
+    }
+
+    fn violation(&self, entry: &Policy) -> Option<String> {
+        if entry.position_comply(self.0) {
+            return None;
+        }
+
+        let (first, second) = entry.bounds();
+
+        match entry.at(first, self.0) {
+            true => Some(format!(
+                "'{}' at both positions {} and {}",
+                entry.character(),
+                first,
+                second
+            )),
+            false => Some(format!(
+                "'{}' at neither position {} nor {}",
+                entry.character(),
+                first,
+                second
+            )),
+        }
+    }
+}
+
+pub struct Length {
+    pub min: usize,
+    pub max: usize,
+}
+
+impl PasswordPolicy for Length {
+    fn name(&self) -> String {
+        format!("length:{}-{}", self.min, self.max)
+    }
+
+    fn violation(&self, entry: &Policy) -> Option<String> {
+        let length = entry.password().chars().count();
+
+        match (self.min..=self.max).contains(&length) {
+            true => None,
+            false => Some(format!(
+                "length {}, expected {}-{}",
+                length, self.min, self.max
+            )),
+        }
+    }
+}
+
+pub struct Forbidden(pub Vec<char>);
+
+impl PasswordPolicy for Forbidden {
+    fn name(&self) -> String {
+        format!("forbid:{}", self.0.iter().collect::<String>())
+    }
+
+    fn violation(&self, entry: &Policy) -> Option<String> {
+        entry
+            .password()
+            .chars()
+            .find(|c| self.0.contains(c))
+            .map(|c| format!("contains forbidden '{}'", c))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharClass {
+    Lowercase,
+    Uppercase,
+    Digit,
+    Symbol,
+}
+
+impl CharClass {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            CharClass::Lowercase => c.is_lowercase(),
+            CharClass::Uppercase => c.is_uppercase(),
+            CharClass::Digit => c.is_numeric(),
+            CharClass::Symbol => !c.is_alphanumeric() && !c.is_whitespace(),
+        }
+    }
+}
+
+impl fmt::Display for CharClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            CharClass::Lowercase => "lower",
+            CharClass::Uppercase => "upper",
+            CharClass::Digit => "digit",
+            CharClass::Symbol => "symbol",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+pub struct Required(pub Vec<CharClass>);
+
+impl PasswordPolicy for Required {
+    fn name(&self) -> String {
+        let classes: Vec<String> = self.0.iter().map(CharClass::to_string).collect();
+        format!("require:{}", classes.join(","))
+    }
+
+    fn violation(&self, entry: &Policy) -> Option<String> {
+        let missing: Vec<String> = self
+            .0
+            .iter()
+            .filter(|class| !entry.password().chars().any(|c| class.matches(c)))
+            .map(CharClass::to_string)
+            .collect();
+
+        match missing.is_empty() {
+            true => None,
+            false => Some(format!("missing {}", missing.join(", "))),
+        }
+    }
+}
+
+fn parse_positions(positions: Option<&str>) -> Result<Positions> {
+    match positions {
+        None => Ok(Positions::Auto),
+        Some("bytes") => Ok(Positions::Bytes),
+        Some("chars") => Ok(Positions::Chars),
+        Some("graphemes") => Ok(Positions::Graphemes),
+        Some(other) => Err(Error::invalid_argument(format!(
+            "Unknown position semantics '{}'",
+            other
+        ))),
+    }
+}
+
+fn parse_class(class: &str) -> Result<CharClass> {
+    match class {
+        "lower" => Ok(CharClass::Lowercase),
+        "upper" => Ok(CharClass::Uppercase),
+        "digit" => Ok(CharClass::Digit),
+        "symbol" => Ok(CharClass::Symbol),
+        _ => Err(Error::invalid_argument(format!(
+            "Unknown character class '{}'",
+            class
+        ))),
+    }
+}
+
+// Builds a policy from specs such as `sled`, `toboggan:graphemes`,
+// `length:8-64`, `forbid:xyz` or `require:lower,digit`.
+pub fn policy(spec: &str) -> Result<Box<dyn PasswordPolicy>> {
+    let (name, argument) = match spec.find(':') {
+        Some(separator) => (&spec[..separator], Some(&spec[separator + 1..])),
+        None => (spec, None),
+    };
+
+    match (name, argument) {
+        ("sled", positions) => Ok(Box::new(SledRental(parse_positions(positions)?))),
+        ("toboggan", positions) => Ok(Box::new(Toboggan(parse_positions(positions)?))),
+        ("length", Some(range)) => match range.find('-') {
+            Some(dash) => {
+                let min = parse_value(&range[..dash])?;
+                let max = parse_value(&range[dash + 1..])?;
+                Ok(Box::new(Length { min, max }))
+            }
+            None => Err(Error::invalid_argument(format!(
+                "Invalid length range '{}'",
+                range
+            ))),
+        },
+        ("forbid", Some(characters)) if !characters.is_empty() => {
+            Ok(Box::new(Forbidden(characters.chars().collect())))
+        }
+        ("require", Some(classes)) => Ok(Box::new(Required(
+            classes.split(',').map(parse_class).collect::<Result<_>>()?,
+        ))),
+        _ => Err(Error::invalid_argument(format!(
+            "Unknown password policy '{}'",
+            spec
+        ))),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation<'a> {
+    pub line: usize,
+    pub password: &'a str,
+    pub policy: String,
+    pub reason: String,
+}
+
+impl fmt::Display for Violation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}: '{}' breaks {}: {}",
+            self.line, self.password, self.policy, self.reason
+        )
+    }
+}
+
+pub fn audit<'a>(
+    entries: &[Policy<'a>],
+    policies: &[Box<dyn PasswordPolicy>],
+) -> Vec<Violation<'a>> {
+    entries
+        .iter()
+        .enumerate()
+        .flat_map(|(index, entry)| {
+            policies.iter().filter_map(move |policy| {
+                policy.violation(entry).map(|reason| Violation {
+                    line: index + 1,
+                    password: entry.password(),
+                    policy: policy.name(),
+                    reason,
+                })
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    fn violations(spec: &str, input: &str) -> Vec<String> {
+        let entries = parse(input).unwrap();
+
+        audit(&entries, &[policy(spec).unwrap()])
+            .iter()
+            .map(Violation::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn explains_the_original_policies() {
+        let input = include_str!("../input/test.txt");
+
+        assert_eq!(
+            violations("sled", input),
+            vec!["line 2: 'cdefg' breaks sled: 'b' appears 0 times, expected 1-3"]
+        );
+        assert_eq!(
+            violations("toboggan", input),
+            vec![
+                "line 2: 'cdefg' breaks toboggan: 'b' at neither position 1 nor 3",
+                "line 3: 'ccccccccc' breaks toboggan: 'c' at both positions 2 and 9",
+            ]
+        );
+    }
+
+    #[test]
+    fn checks_configurable_policies() {
+        let input = "1-3 a: abcde\n1-3 a: Ab3!efgh\n";
+
+        assert_eq!(
+            violations("length:6-20", input),
+            vec!["line 1: 'abcde' breaks length:6-20: length 5, expected 6-20"]
+        );
+        assert_eq!(
+            violations("forbid:xyz!", input),
+            vec!["line 2: 'Ab3!efgh' breaks forbid:xyz!: contains forbidden '!'"]
+        );
+        assert_eq!(
+            violations("require:upper,digit,symbol", input),
+            vec!["line 1: 'abcde' breaks require:upper,digit,symbol: missing upper, digit, symbol"]
+        );
+    }
+
+    // Only uses the public accessors, like a policy defined in another crate.
+    struct NotLeading;
+
+    impl PasswordPolicy for NotLeading {
+        fn name(&self) -> String {
+            String::from("not-leading")
+        }
+
+        fn violation(&self, entry: &Policy) -> Option<String> {
+            match entry.password().starts_with(entry.character()) {
+                true => Some(format!("starts with '{}'", entry.character())),
+                false => None,
+            }
+        }
+    }
+
+    #[test]
+    fn accepts_custom_policies() {
+        let entries = parse(include_str!("../input/test.txt")).unwrap();
+        let policies: Vec<Box<dyn PasswordPolicy>> = vec![Box::new(NotLeading)];
+
+        assert_eq!(entries[2].bounds(), (2, 9));
+        assert_eq!(
+            audit(&entries, &policies)
+                .iter()
+                .map(Violation::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "line 1: 'abcde' breaks not-leading: starts with 'a'",
+                "line 3: 'ccccccccc' breaks not-leading: starts with 'c'",
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_policies() {
+        assert!(policy("length:6").is_err());
+        assert!(policy("require:emoji").is_err());
+        assert!(policy("sled:1").is_err());
+        assert!(policy("toboggan:graphemes").is_ok());
+        assert!(policy("strict").is_err());
+    }
+}
@@ -0,0 +1,196 @@
+use common::input::grid;
+use common::{Error, Result};
+use std::fmt;
+use std::ops::RangeInclusive;
+
+// `right` may be negative to go left, and any `right / down` ratio is allowed
+// as long as the toboggan keeps going down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slope {
+    pub right: i64,
+    pub down: usize,
+}
+
+impl Slope {
+    pub fn new(right: i64, down: usize) -> Result<Self> {
+        match down {
+            0 => Err(Error::invalid_argument(
+                "Slope must move down at least one row",
+            )),
+            _ => Ok(Self { right, down }),
+        }
+    }
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "right {}, down {}", self.right, self.down)
+    }
+}
+
+// `column` is not wrapped, so it tells how far the pattern had to repeat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Visit {
+    pub row: usize,
+    pub column: i64,
+    pub tree: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub slope: Slope,
+    pub visits: Vec<Visit>,
+}
+
+impl Route {
+    pub fn trees(&self) -> usize {
+        self.visits.iter().filter(|visit| visit.tree).count()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Objective {
+    FewestTrees,
+    MostTrees,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Forest {
+    trees: Vec<Vec<bool>>,
+}
+
+impl Forest {
+    pub fn new(input: &str) -> Result<Self> {
+        let squares = grid(input)?;
+        let mut trees = Vec::with_capacity(squares.len());
+
+        for (row, line) in squares.iter().enumerate() {
+            trees.push(
+                line.iter()
+                    .enumerate()
+                    .map(|(column, &square)| match square {
+                        '#' => Ok(true),
+                        '.' => Ok(false),
+                        _ => Err(Error::parse(format!("Invalid square '{}'", square))
+                            .at_line(row + 1)
+                            .at_column(column + 1)),
+                    })
+                    .collect::<Result<_>>()?,
+            );
+        }
+
+        Ok(Self { trees })
+    }
+
+    pub fn width(&self) -> usize {
+        self.trees[0].len()
+    }
+
+    pub fn height(&self) -> usize {
+        self.trees.len()
+    }
+
+    // The pattern repeats to the left and right forever.
+    pub fn is_tree(&self, row: usize, column: i64) -> bool {
+        self.trees[row][column.rem_euclid(self.width() as i64) as usize]
+    }
+
+    // Every square the toboggan lands on after leaving the top-left corner.
+    pub fn route(&self, slope: Slope) -> Route {
+        let visits = (1..)
+            .map(|moves: usize| (moves * slope.down, moves as i64 * slope.right))
+            .take_while(|&(row, _)| row < self.height())
+            .map(|(row, column)| Visit {
+                row,
+                column,
+                tree: self.is_tree(row, column),
+            })
+            .collect();
+
+        Route { slope, visits }
+    }
+
+    // Tries every slope in the space and keeps the first best route found,
+    // scanning `down` first and then `right`, both in ascending order.
+    pub fn best_route(
+        &self,
+        rights: RangeInclusive<i64>,
+        downs: RangeInclusive<usize>,
+        objective: Objective,
+    ) -> Result<Route> {
+        let mut best: Option<Route> = None;
+
+        for down in downs.filter(|&down| down > 0) {
+            for right in rights.clone() {
+                let route = self.route(Slope { right, down });
+                let better = match (&best, objective) {
+                    (None, _) => true,
+                    (Some(best), Objective::FewestTrees) => route.trees() < best.trees(),
+                    (Some(best), Objective::MostTrees) => route.trees() > best.trees(),
+                };
+
+                if better {
+                    best = Some(route);
+                }
+            }
+        }
+
+        best.ok_or_else(|| Error::invalid_argument("No slope to explore"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.txt");
+
+    #[test]
+    fn lists_visited_squares() {
+        let forest = Forest::new(EXAMPLE).unwrap();
+        let route = forest.route(Slope::new(3, 1).unwrap());
+
+        assert_eq!(route.visits.len(), 10);
+        assert_eq!(
+            route.visits[0],
+            Visit {
+                row: 1,
+                column: 3,
+                tree: false
+            }
+        );
+        assert_eq!(route.visits[9].column, 30);
+        assert_eq!(route.trees(), 7);
+    }
+
+    #[test]
+    fn goes_left() {
+        let forest = Forest::new(EXAMPLE).unwrap();
+        // Eight to the left lands on the same squares as three to the right.
+        let route = forest.route(Slope::new(-8, 1).unwrap());
+
+        assert_eq!(route.trees(), 7);
+        assert_eq!(route.visits[1].column, -16);
+        assert!(forest.is_tree(1, -11));
+        assert_eq!(forest.route(Slope::new(-1, 3).unwrap()).visits.len(), 3);
+    }
+
+    #[test]
+    fn searches_the_slope_space() {
+        let forest = Forest::new(EXAMPLE).unwrap();
+        let most = forest
+            .best_route(0..=7, 1..=2, Objective::MostTrees)
+            .unwrap();
+        let fewest = forest
+            .best_route(-3..=3, 0..=2, Objective::FewestTrees)
+            .unwrap();
+
+        assert_eq!(most.slope, Slope { right: 3, down: 1 });
+        assert_eq!(most.trees(), 7);
+        assert_eq!(fewest.slope, Slope { right: 2, down: 1 });
+        assert_eq!(fewest.trees(), 1);
+        assert!(forest
+            .best_route(0..=3, 0..=0, Objective::MostTrees)
+            .is_err());
+    }
+}
@@ -0,0 +1,77 @@
+use common::{Result, Solution};
+pub use forest::{Forest, Objective, Route, Slope, Visit};
+pub use render::{render, Style};
+
+pub mod forest;
+pub mod render;
+
+pub fn solve(forest: &Forest, step: usize, down: usize) -> Result<u64> {
+    let slope = Slope::new(step as i64, down)?;
+    Ok(forest.route(slope).trees() as u64)
+}
+
+pub fn part1(forest: &Forest) -> Result<u64> {
+    solve(forest, 3, 1)
+}
+
+pub fn part2(forest: &Forest) -> Result<u64> {
+    [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+        .iter()
+        .try_fold(1, |acc, &(right, down)| {
+            Ok(acc * forest.route(Slope::new(right, down)?).trees() as u64)
+        })
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Forest;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Forest::new(input)
+    }
+
+    fn part1(forest: &Self::Input<'_>) -> Result<Self::Part1> {
+        part1(forest)
+    }
+
+    fn part2(forest: &Self::Input<'_>) -> Result<Self::Part2> {
+        part2(forest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.txt");
+
+    #[test]
+    fn example() {
+        let forest = Forest::new(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&forest), Ok(7));
+        assert_eq!(part2(&forest), Ok(336));
+    }
+
+    #[test]
+    fn example_slopes() {
+        let forest = Forest::new(EXAMPLE).unwrap();
+
+        assert_eq!(solve(&forest, 1, 1), Ok(2));
+        assert_eq!(solve(&forest, 5, 1), Ok(3));
+        assert_eq!(solve(&forest, 7, 1), Ok(4));
+        assert_eq!(solve(&forest, 1, 2), Ok(2));
+    }
+
+    #[test]
+    fn rejects_zero_down_step() {
+        let forest = Forest::new(EXAMPLE).unwrap();
+
+        assert!(solve(&forest, 1, 0).is_err());
+    }
+}
@@ -1,33 +1,50 @@
-use std::io::{self, Read};
+use common::input;
+use common::{parse_value, Error, Result};
+use day03::{render, Day03, Forest, Slope, Style};
+use std::env;
+use std::process;
 
-pub fn solve(input: &str, step: usize, down: usize) -> u64 {
-    let mut index = step;
-    let mut trees = 0;
-
-    for line in input.lines().skip(down).step_by(down) {
-        let len = line.len();
-        index = index % len;
-        let mut line = line.chars();
-
-        if line.nth(index).unwrap() == '#' {
-            trees += 1;
+// `day03 render <right> <down> [--ansi]` draws the route taken through the
+// map on stdin.
+fn run_render(args: &[String]) -> Result<String> {
+    let (slope, style) = match args {
+        [right, down] => (
+            Slope::new(parse_value(right)?, parse_value(down)?)?,
+            Style::Plain,
+        ),
+        [right, down, ansi] if ansi == "--ansi" => (
+            Slope::new(parse_value(right)?, parse_value(down)?)?,
+            Style::Ansi,
+        ),
+        _ => {
+            return Err(Error::invalid_argument(
+                "Usage: day03 render <right> <down> [--ansi]",
+            ))
         }
+    };
+    let input = input::stdin()?;
+    let forest = Forest::new(&input)?;
+    let route = forest.route(slope);
 
-        index += step;
-    }
-
-    trees
+    Ok(format!(
+        "{}{} trees hit going {}",
+        render(&forest, &route, style),
+        route.trees(),
+        slope
+    ))
 }
 
 fn main() {
-    let mut input = String::new();
-    io::stdin().read_to_string(&mut input).unwrap();
-    println!("{:?}", solve(&input, 3, 1));
+    let args: Vec<String> = env::args().skip(1).collect();
 
-    let total_trees: u64 = vec![(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
-        .iter()
-        .fold(1, |acc, (step, down)| {
-            acc * solve(&input, *step as usize, *down as usize)
-        });
-    println!("{:?}", total_trees);
+    match args.split_first() {
+        Some((command, args)) if command == "render" => match run_render(args) {
+            Ok(map) => println!("{}", map),
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+        },
+        _ => common::main::<Day03>(),
+    }
 }
@@ -0,0 +1,105 @@
+use crate::{Forest, Route};
+use std::collections::HashMap;
+
+const RESET: &str = "\x1b[0m";
+const GREEN: &str = "\x1b[32m";
+const RED: &str = "\x1b[1;31m";
+const DIM: &str = "\x1b[2m";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    Plain,
+    Ansi,
+}
+
+fn paint(square: char, colour: &str, style: Style) -> String {
+    match style {
+        Style::Plain => square.to_string(),
+        Style::Ansi => format!("{}{}{}", colour, square, RESET),
+    }
+}
+
+// Draws the map repeated sideways over every tile the route crosses, marking
+// open squares it lands on with `O` and trees it hits with `X`.
+pub fn render(forest: &Forest, route: &Route, style: Style) -> String {
+    let width = forest.width() as i64;
+    let visits: HashMap<(usize, i64), bool> = route
+        .visits
+        .iter()
+        .map(|visit| ((visit.row, visit.column), visit.tree))
+        .collect();
+    let columns = route.visits.iter().map(|visit| visit.column);
+    let first = columns.clone().min().unwrap_or(0).min(0).div_euclid(width) * width;
+    let last = (columns.max().unwrap_or(0).max(0).div_euclid(width) + 1) * width;
+    let mut map = String::new();
+
+    for row in 0..forest.height() {
+        for column in first..last {
+            let square = match (visits.get(&(row, column)), forest.is_tree(row, column)) {
+                (Some(true), _) => paint('X', RED, style),
+                (Some(false), _) => paint('O', GREEN, style),
+                (None, true) => paint('#', DIM, style),
+                (None, false) => paint('.', DIM, style),
+            };
+
+            map.push_str(&square);
+        }
+
+        map.push('\n');
+    }
+
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Slope;
+
+    const EXAMPLE: &str = include_str!("../input/test.txt");
+
+    fn render_example(right: i64, down: usize, style: Style) -> String {
+        let forest = Forest::new(EXAMPLE).unwrap();
+        render(
+            &forest,
+            &forest.route(Slope::new(right, down).unwrap()),
+            style,
+        )
+    }
+
+    #[test]
+    fn marks_the_route_like_the_puzzle() {
+        assert_eq!(
+            render_example(3, 1, Style::Plain),
+            "..##.........##.........##.......\n\
+             #..O#...#..#...#...#..#...#...#..\n\
+             .#....X..#..#....#..#..#....#..#.\n\
+             ..#.#...#O#..#.#...#.#..#.#...#.#\n\
+             .#...##..#..X...##..#..#...##..#.\n\
+             ..#.##.......#.X#.......#.##.....\n\
+             .#.#.#....#.#.#.#.O..#.#.#.#....#\n\
+             .#........#.#........X.#........#\n\
+             #.##...#...#.##...#...#.X#...#...\n\
+             #...##....##...##....##...#X....#\n\
+             .#..#...#.#.#..#...#.#.#..#...X.#\n"
+        );
+    }
+
+    #[test]
+    fn extends_to_the_left() {
+        let map = render_example(-1, 5, Style::Plain);
+        let rows: Vec<&str> = map.lines().collect();
+
+        assert_eq!(rows[0].len(), 22);
+        assert_eq!(&rows[5][9..12], ".O.");
+        assert_eq!(&rows[10][8..11], "#O#");
+    }
+
+    #[test]
+    fn colours_marks() {
+        let map = render_example(3, 1, Style::Ansi);
+
+        assert!(map.contains(&format!("{}O{}", GREEN, RESET)));
+        assert!(map.contains(&format!("{}X{}", RED, RESET)));
+    }
+}
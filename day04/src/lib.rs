@@ -0,0 +1,161 @@
+pub use passport::{EyeColor, HairColor, Height, Passport, Rejection};
+pub use schema::Schema;
+
+use common::input::groups;
+use common::{column_of, Context, Error, Result, Solution};
+use std::collections::HashMap;
+
+#[macro_use]
+extern crate lazy_static;
+
+pub mod passport;
+pub mod schema;
+
+lazy_static! {
+    static ref PASSPORT: Schema =
+        Schema::from_toml(include_str!("../schema/passport.toml")).unwrap();
+}
+
+pub fn valid(data: &HashMap<&str, &str>) -> bool {
+    PASSPORT.valid(data)
+}
+
+pub fn parse_entry(entry: &str) -> Result<(&str, &str)> {
+    let mut data = entry.splitn(2, ':');
+
+    match (data.next(), data.next()) {
+        (Some(field), Some(value)) if !field.is_empty() => Ok((field, value)),
+        _ => Err(Error::parse(format!(
+            "Expected '<field>:<value>', found '{}'",
+            entry
+        ))),
+    }
+}
+
+fn entries(line: &str, row: usize) -> impl Iterator<Item = Result<(&str, &str)>> {
+    line.split_whitespace().map(move |entry| {
+        parse_entry(entry)
+            .at_line(row + 1)
+            .at_column(column_of(line, entry))
+    })
+}
+
+pub fn passports(input: &str) -> impl Iterator<Item = Result<HashMap<&str, &str>>> {
+    groups(input).map(|(first_line, group)| {
+        let mut passport = HashMap::new();
+
+        for (index, line) in group.lines().enumerate() {
+            for entry in entries(line, first_line - 1 + index) {
+                let (field, value) = entry?;
+                passport.insert(field, value);
+            }
+        }
+
+        Ok(passport)
+    })
+}
+
+pub fn parse(input: &str) -> Result<Vec<HashMap<&str, &str>>> {
+    passports(input).collect()
+}
+
+pub fn part1(passports: &[HashMap<&str, &str>]) -> u64 {
+    passports
+        .iter()
+        .filter(|passport| PASSPORT.complete(passport))
+        .count() as u64
+}
+
+pub fn part2(passports: &[HashMap<&str, &str>]) -> u64 {
+    passports.iter().filter(|passport| valid(passport)).count() as u64
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<HashMap<&'a str, &'a str>>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(passports: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(part1(passports))
+    }
+
+    fn part2(passports: &Self::Input<'_>) -> Result<Self::Part2> {
+        Ok(part2(passports))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.txt");
+    const EXAMPLE_2: &str = include_str!("../input/test2.txt");
+
+    #[test]
+    fn example() {
+        let example = Day04::parse(EXAMPLE).unwrap();
+        let example_2 = Day04::parse(EXAMPLE_2).unwrap();
+
+        assert_eq!(Day04::part1(&example), Ok(2));
+        assert_eq!(Day04::part1(&example_2), Ok(8));
+        assert_eq!(Day04::part2(&example_2), Ok(4));
+    }
+
+    #[test]
+    fn field_rules() {
+        let passport = |fields: &[(&'static str, &'static str)]| {
+            let mut data: HashMap<&str, &str> = [
+                ("byr", "2002"),
+                ("iyr", "2015"),
+                ("eyr", "2025"),
+                ("hgt", "60in"),
+                ("hcl", "#123abc"),
+                ("ecl", "brn"),
+                ("pid", "000000001"),
+            ]
+            .iter()
+            .cloned()
+            .collect();
+            data.extend(fields.iter().cloned());
+            valid(&data)
+        };
+
+        assert!(passport(&[]));
+        assert!(!passport(&[("byr", "2003")]));
+        assert!(passport(&[("hgt", "190cm")]));
+        assert!(!passport(&[("hgt", "190in")]));
+        assert!(!passport(&[("hgt", "190")]));
+        assert!(!passport(&[("hcl", "#123abz")]));
+        assert!(!passport(&[("hcl", "123abc")]));
+        assert!(!passport(&[("ecl", "wat")]));
+        assert!(!passport(&[("pid", "0123456789")]));
+    }
+
+    #[test]
+    fn reports_malformed_entries() {
+        let error = parse("ecl:gry pid\n").unwrap_err();
+
+        assert_eq!(error.line, Some(1));
+        assert_eq!(error.column, Some(9));
+
+        let error = parse("ecl:gry\n\n\nhcl:#fffffd\niyr\n").unwrap_err();
+
+        assert_eq!(error.line, Some(5));
+    }
+
+    #[test]
+    fn counts_the_last_passport_without_a_trailing_newline() {
+        let crlf = EXAMPLE.replace('\n', "\r\n");
+
+        assert_eq!(parse(EXAMPLE.trim_end()).map(|p| part1(&p)), Ok(2));
+        assert_eq!(parse(&crlf).map(|p| part1(&p)), Ok(2));
+    }
+}
@@ -1,138 +1,65 @@
-use regex::Regex;
-use std::collections::{HashMap, HashSet};
-use std::io::{self, Read};
-
-#[macro_use]
-extern crate lazy_static;
-
-lazy_static! {
-    static ref REQUIRED_FIELDS: HashSet<&'static str> = {
-        let mut required_fields = HashSet::new();
-        required_fields.insert("byr");
-        required_fields.insert("iyr");
-        required_fields.insert("eyr");
-        required_fields.insert("hgt");
-        required_fields.insert("hcl");
-        required_fields.insert("ecl");
-        required_fields.insert("pid");
-        required_fields
-    };
-}
-
-macro_rules! validate_or_return {
-    ( $e:expr ) => {
-        match $e {
-            true => true,
-            false => return false,
-        }
-    };
-}
-
-pub fn rule(data: &str, constraint: fn(u64) -> bool) -> bool {
-    match data.parse::<u64>() {
-        Ok(value) => constraint(value),
-        _ => false,
-    }
-}
-
-pub fn valid(data: &HashMap<&str, &str>) -> bool {
-    lazy_static! {
-        static ref HGT_RE: Regex = Regex::new(r"^(\d{3})cm$|^(\d{2})in$").unwrap();
-        static ref HCL_RE: Regex = Regex::new(r"^#[0-9a-f]{6}$").unwrap();
-        static ref ECL_RE: Regex =
-            Regex::new(r"^amb$|^blu$|^brn$|^gry$|^grn$|^hzl$|^oth$").unwrap();
-        static ref PID_RE: Regex = Regex::new(r"^\d{9}$").unwrap();
-    }
-
-    if !HGT_RE.is_match(&data["hgt"]) {
-        return false;
-    }
-
-    if !HCL_RE.is_match(&data["hcl"]) {
-        return false;
-    }
-
-    if !ECL_RE.is_match(&data["ecl"]) {
-        return false;
-    }
-
-    if !PID_RE.is_match(&data["pid"]) {
-        return false;
-    }
-
-    validate_or_return!(rule(data["byr"], |year| year >= 1920 && year <= 2002));
-    validate_or_return!(rule(data["iyr"], |year| year >= 2010 && year <= 2020));
-    validate_or_return!(rule(data["eyr"], |year| year >= 2020 && year <= 2030));
-
-    let caps = HGT_RE.captures(&data["hgt"]).unwrap();
-    match caps.get(1) {
-        Some(height_cm) => {
-            validate_or_return!(rule(height_cm.as_str(), |size| (size >= 150 && size <= 193)));
-        }
-        None => {
-            let height_in = caps.get(2).unwrap();
-            validate_or_return!(rule(height_in.as_str(), |size| (size >= 59 && size <= 76)));
-        }
-    };
-
-    true
-}
-
-pub fn part1(input: &str) -> u64 {
-    let mut passport_fields: HashSet<&str> = HashSet::new();
-    let mut count: u64 = 0;
-
-    for line in input.lines() {
-        if line.is_empty() {
-            if REQUIRED_FIELDS.is_subset(&passport_fields) {
-                count += 1;
-            }
-            passport_fields = HashSet::new();
-            continue;
+use common::input;
+use common::Result;
+use day04::{passports, Day04, Passport, Schema};
+use std::env;
+use std::path::Path;
+use std::process;
+
+// `day04 validate <schema.toml|schema.json>` checks the documents on stdin
+// against rules loaded at runtime.
+fn run_validate(schema: &str) -> Result<usize> {
+    let schema = Schema::load(Path::new(schema))?;
+    let input = input::stdin()?;
+    let mut valid = 0;
+
+    for (index, document) in passports(&input).enumerate() {
+        let violations = schema.violations(&document?);
+
+        for (field, reason) in &violations {
+            println!("document {}: {}: {}", index + 1, field, reason);
         }
 
-        for entry in line.split_whitespace() {
-            let field: &str = entry.split(":").collect::<Vec<&str>>().first().unwrap();
-            passport_fields.insert(field);
+        if violations.is_empty() {
+            valid += 1;
         }
     }
 
-    count
+    Ok(valid)
 }
 
-pub fn part2(input: &str) -> u64 {
-    let mut passport_fields: HashSet<&str> = HashSet::new();
-    let mut passport_data: HashMap<&str, &str> = HashMap::new();
-    let mut count: u64 = 0;
-
-    for line in input.lines() {
-        if line.is_empty() {
-            if REQUIRED_FIELDS.is_subset(&passport_fields) && valid(&passport_data) {
-                count += 1;
-            }
+// `day04 explain` lists why each rejected passport on stdin is invalid.
+fn run_explain() -> Result<usize> {
+    let input = input::stdin()?;
+    let mut valid = 0;
 
-            passport_fields = HashSet::new();
-            passport_data = HashMap::new();
-
-            continue;
-        }
-
-        for entry in line.split_whitespace() {
-            let data = entry.split(":").collect::<Vec<&str>>();
-            let field = data.first().unwrap();
-            let value = data.last().unwrap();
-            passport_fields.insert(field);
-            passport_data.insert(field, value);
+    for (index, fields) in passports(&input).enumerate() {
+        match Passport::from_fields(&fields?) {
+            Ok(_) => valid += 1,
+            Err(rejection) => println!("passport {}: {}", index + 1, rejection),
         }
     }
 
-    count
+    Ok(valid)
 }
 
 fn main() {
-    let mut input = String::new();
-    io::stdin().read_to_string(&mut input).unwrap();
-    input.push('\n');
-    println!("{:?}", part1(&input));
-    println!("{:?}", part2(&input));
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.as_slice() {
+        [command, schema] if command == "validate" => match run_validate(schema) {
+            Ok(valid) => println!("{} valid documents", valid),
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+        },
+        [command] if command == "explain" => match run_explain() {
+            Ok(valid) => println!("{} valid passports", valid),
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+        },
+        _ => common::main::<Day04>(),
+    }
 }
@@ -0,0 +1,350 @@
+use crate::{parse_entry, Schema, PASSPORT};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Height {
+    Centimeters(u32),
+    Inches(u32),
+}
+
+impl FromStr for Height {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let split = value
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(value.len());
+        let size = value[..split].parse::<u32>();
+
+        match (size, &value[split..]) {
+            (Ok(size), "cm") => Ok(Height::Centimeters(size)),
+            (Ok(size), "in") => Ok(Height::Inches(size)),
+            _ => Err(format!("'{}' is not a height in cm or in", value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EyeColor {
+    Amber,
+    Blue,
+    Brown,
+    Gray,
+    Green,
+    Hazel,
+    Other,
+}
+
+impl FromStr for EyeColor {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "amb" => Ok(EyeColor::Amber),
+            "blu" => Ok(EyeColor::Blue),
+            "brn" => Ok(EyeColor::Brown),
+            "gry" => Ok(EyeColor::Gray),
+            "grn" => Ok(EyeColor::Green),
+            "hzl" => Ok(EyeColor::Hazel),
+            "oth" => Ok(EyeColor::Other),
+            _ => Err(format!("'{}' is not an eye color", value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HairColor {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+impl FromStr for HairColor {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let hex = match value.strip_prefix('#') {
+            Some(hex)
+                if hex.len() == 6
+                    && hex.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')) =>
+            {
+                hex
+            }
+            _ => return Err(format!("'{}' is not a color like #1a2b3c", value)),
+        };
+        let channel = |at: usize| u8::from_str_radix(&hex[at..at + 2], 16).unwrap();
+
+        Ok(Self {
+            red: channel(0),
+            green: channel(2),
+            blue: channel(4),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Passport {
+    pub birth_year: u16,
+    pub issue_year: u16,
+    pub expiration_year: u16,
+    pub height: Height,
+    pub hair_color: HairColor,
+    pub eye_color: EyeColor,
+    pub passport_id: String,
+    pub country_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    pub field: String,
+    pub reason: String,
+}
+
+// Every reason a record could not become a valid passport.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rejection(pub Vec<Problem>);
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let problems: Vec<String> = self
+            .0
+            .iter()
+            .map(|problem| format!("{}: {}", problem.field, problem.reason))
+            .collect();
+
+        write!(f, "{}", problems.join("; "))
+    }
+}
+
+fn year(value: &str) -> Result<u16, String> {
+    value
+        .parse()
+        .map_err(|_| format!("'{}' is not a year", value))
+}
+
+fn check<T, F>(
+    fields: &HashMap<&str, &str>,
+    problems: &mut Vec<Problem>,
+    name: &str,
+    parse: F,
+) -> Option<T>
+where
+    F: Fn(&str) -> Result<T, String>,
+{
+    // The schema already explained what is wrong with this field.
+    if problems.iter().any(|problem| problem.field == name) {
+        return None;
+    }
+
+    let parsed = match fields.get(name) {
+        Some(value) => parse(value),
+        None => Err(String::from("missing")),
+    };
+
+    match parsed {
+        Ok(value) => Some(value),
+        Err(reason) => {
+            problems.push(Problem {
+                field: name.to_string(),
+                reason,
+            });
+            None
+        }
+    }
+}
+
+impl Passport {
+    // The schema decides which values are valid, so `day04 explain` always
+    // agrees with `part2` and `day04 validate`. The conversions only reject
+    // values a looser schema allows but the typed fields can't represent.
+    pub fn from_fields_with(
+        fields: &HashMap<&str, &str>,
+        schema: &Schema,
+    ) -> Result<Self, Rejection> {
+        let mut problems: Vec<Problem> = schema
+            .violations(fields)
+            .into_iter()
+            .map(|(field, reason)| Problem {
+                field: field.to_string(),
+                reason,
+            })
+            .collect();
+        let birth_year = check(fields, &mut problems, "byr", year);
+        let issue_year = check(fields, &mut problems, "iyr", year);
+        let expiration_year = check(fields, &mut problems, "eyr", year);
+        let height = check(fields, &mut problems, "hgt", str::parse);
+        let hair_color = check(fields, &mut problems, "hcl", str::parse);
+        let eye_color = check(fields, &mut problems, "ecl", str::parse);
+        let passport_id = check(fields, &mut problems, "pid", |pid| Ok(pid.to_string()));
+
+        match (
+            birth_year,
+            issue_year,
+            expiration_year,
+            height,
+            hair_color,
+            eye_color,
+            passport_id,
+        ) {
+            (
+                Some(birth_year),
+                Some(issue_year),
+                Some(expiration_year),
+                Some(height),
+                Some(hair_color),
+                Some(eye_color),
+                Some(passport_id),
+            ) if problems.is_empty() => Ok(Self {
+                birth_year,
+                issue_year,
+                expiration_year,
+                height,
+                hair_color,
+                eye_color,
+                passport_id,
+                country_id: fields.get("cid").map(|cid| cid.to_string()),
+            }),
+            _ => Err(Rejection(problems)),
+        }
+    }
+
+    // Validates against the built-in `schema/passport.toml`.
+    pub fn from_fields(fields: &HashMap<&str, &str>) -> Result<Self, Rejection> {
+        Self::from_fields_with(fields, &PASSPORT)
+    }
+}
+
+// Parses a single record, with its fields separated by spaces or newlines.
+impl FromStr for Passport {
+    type Err = Rejection;
+
+    fn from_str(record: &str) -> Result<Self, Self::Err> {
+        let mut fields = HashMap::new();
+        let mut problems = Vec::new();
+
+        for entry in record.split_whitespace() {
+            match parse_entry(entry) {
+                Ok((field, value)) => {
+                    fields.insert(field, value);
+                }
+                Err(error) => problems.push(Problem {
+                    field: entry.to_string(),
+                    reason: error.message,
+                }),
+            }
+        }
+
+        match Self::from_fields(&fields) {
+            Ok(passport) if problems.is_empty() => Ok(passport),
+            Ok(_) => Err(Rejection(problems)),
+            Err(Rejection(rest)) => {
+                problems.extend(rest);
+                Err(Rejection(problems))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID: &str = "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980\nhcl:#623a2f";
+
+    fn reasons(record: &str) -> Vec<String> {
+        match record.parse::<Passport>() {
+            Ok(_) => vec![],
+            Err(rejection) => rejection
+                .0
+                .into_iter()
+                .map(|problem| format!("{}: {}", problem.field, problem.reason))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn parses_typed_fields() {
+        let passport: Passport = format!("{} cid:88", VALID).parse().unwrap();
+
+        assert_eq!(passport.birth_year, 1980);
+        assert_eq!(passport.height, Height::Inches(74));
+        assert_eq!(passport.eye_color, EyeColor::Green);
+        assert_eq!(
+            passport.hair_color,
+            HairColor {
+                red: 0x62,
+                green: 0x3a,
+                blue: 0x2f
+            }
+        );
+        assert_eq!(passport.passport_id, "087499704");
+        assert_eq!(passport.country_id, Some(String::from("88")));
+    }
+
+    #[test]
+    fn lists_every_problem() {
+        assert_eq!(reasons(VALID), Vec::<String>::new());
+        assert_eq!(
+            reasons("eyr:1972 cid:100 hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926"),
+            vec![
+                "eyr: 1972 is not within 2020-2030",
+                "hgt: '170' needs one of the units cm, in",
+                "pid: '186cm' does not match ^(?:\\d{9})$",
+            ]
+        );
+        assert_eq!(
+            reasons("hcl:dab227 iyr:2012 ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:02002"),
+            vec![
+                "byr: '02002' does not match ^(?:\\d{4})$",
+                "hcl: 'dab227' does not match ^(?:#[0-9a-f]{6})$",
+            ]
+        );
+        assert_eq!(
+            reasons("hgt:59cm ecl:zzz iyr"),
+            vec![
+                "iyr: Expected '<field>:<value>', found 'iyr'",
+                "byr: missing",
+                "ecl: 'zzz' is not one of amb, blu, brn, gry, grn, hzl, oth",
+                "eyr: missing",
+                "hcl: missing",
+                "hgt: 59 is not within 150-193",
+                "iyr: missing",
+                "pid: missing",
+            ]
+        );
+    }
+
+    fn fields(record: &str) -> HashMap<&str, &str> {
+        record
+            .split_whitespace()
+            .map(|entry| parse_entry(entry).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn follows_the_schema() {
+        let loose = include_str!("../schema/passport.toml")
+            .replace("[1920, 2002]", "[1900, 2010]")
+            .replace("'#[0-9a-f]{6}'", "'.+'");
+        let schema = Schema::from_toml(&loose).unwrap();
+        let record = "hcl:#623a2f iyr:2012 ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:2007";
+
+        assert!(Passport::from_fields(&fields(record)).is_err());
+        assert_eq!(
+            Passport::from_fields_with(&fields(record), &schema)
+                .map(|passport| passport.birth_year),
+            Ok(2007)
+        );
+
+        let record = record.replace("#623a2f", "red");
+
+        assert_eq!(
+            Passport::from_fields_with(&fields(&record), &schema)
+                .unwrap_err()
+                .to_string(),
+            "hcl: 'red' is not a color like #1a2b3c"
+        );
+    }
+}
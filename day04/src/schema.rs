@@ -0,0 +1,258 @@
+use common::input::Source;
+use common::{Error, Result};
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+fn required() -> bool {
+    true
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FieldSpec {
+    #[serde(default = "required")]
+    required: bool,
+    pattern: Option<String>,
+    range: Option<[i64; 2]>,
+    units: Option<BTreeMap<String, [i64; 2]>>,
+    one_of: Option<Vec<String>>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Document {
+    fields: BTreeMap<String, FieldSpec>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Constraint {
+    Pattern(Regex),
+    Range(i64, i64),
+    Units(BTreeMap<String, (i64, i64)>),
+    OneOf(Vec<String>),
+}
+
+fn in_range(value: &str, (min, max): (i64, i64)) -> Option<String> {
+    match value.parse::<i64>() {
+        Ok(number) if (min..=max).contains(&number) => None,
+        Ok(number) => Some(format!("{} is not within {}-{}", number, min, max)),
+        Err(_) => Some(format!("'{}' is not a number", value)),
+    }
+}
+
+impl Constraint {
+    // Explains why the value breaks the constraint, or returns None if it holds.
+    pub fn violation(&self, value: &str) -> Option<String> {
+        match self {
+            Constraint::Pattern(pattern) => match pattern.is_match(value) {
+                true => None,
+                false => Some(format!("'{}' does not match {}", value, pattern)),
+            },
+            Constraint::Range(min, max) => in_range(value, (*min, *max)),
+            Constraint::Units(units) => {
+                let split = value
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(value.len());
+
+                match units.get(&value[split..]) {
+                    Some(&range) => in_range(&value[..split], range),
+                    None => Some(format!(
+                        "'{}' needs one of the units {}",
+                        value,
+                        units.keys().cloned().collect::<Vec<_>>().join(", ")
+                    )),
+                }
+            }
+            Constraint::OneOf(values) => match values.iter().any(|allowed| allowed == value) {
+                true => None,
+                false => Some(format!("'{}' is not one of {}", value, values.join(", "))),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Field {
+    pub name: String,
+    pub required: bool,
+    pub constraints: Vec<Constraint>,
+}
+
+impl Field {
+    fn new(name: String, spec: FieldSpec) -> Result<Self> {
+        let mut constraints = Vec::new();
+
+        if let Some(pattern) = spec.pattern {
+            // Patterns must match the whole value.
+            let pattern = Regex::new(&format!("^(?:{})$", pattern))
+                .map_err(|error| Error::invalid_argument(format!("Field '{}': {}", name, error)))?;
+            constraints.push(Constraint::Pattern(pattern));
+        }
+
+        if let Some([min, max]) = spec.range {
+            constraints.push(Constraint::Range(min, max));
+        }
+
+        if let Some(units) = spec.units {
+            constraints.push(Constraint::Units(
+                units
+                    .into_iter()
+                    .map(|(unit, [min, max])| (unit, (min, max)))
+                    .collect(),
+            ));
+        }
+
+        if let Some(values) = spec.one_of {
+            constraints.push(Constraint::OneOf(values));
+        }
+
+        Ok(Self {
+            name,
+            required: spec.required,
+            constraints,
+        })
+    }
+}
+
+// Validation rules for a kind of document, loaded from TOML or JSON such as
+// `schema/passport.toml`. Fields the schema doesn't mention are ignored.
+#[derive(Debug, Clone)]
+pub struct Schema {
+    pub fields: Vec<Field>,
+}
+
+impl Schema {
+    fn new(document: Document) -> Result<Self> {
+        let fields = document
+            .fields
+            .into_iter()
+            .map(|(name, spec)| Field::new(name, spec))
+            .collect::<Result<_>>()?;
+
+        Ok(Self { fields })
+    }
+
+    pub fn from_toml(contents: &str) -> Result<Self> {
+        Self::new(toml::from_str(contents).map_err(|error| Error::parse(error.to_string()))?)
+    }
+
+    pub fn from_json(contents: &str) -> Result<Self> {
+        Self::new(serde_json::from_str(contents).map_err(|error| Error::parse(error.to_string()))?)
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = Source::Path(path.to_path_buf()).load()?;
+
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => Self::from_toml(&contents),
+            Some("json") => Self::from_json(&contents),
+            _ => Err(Error::invalid_argument("Expected a .toml or .json schema")),
+        }
+    }
+
+    pub fn complete(&self, document: &HashMap<&str, &str>) -> bool {
+        self.fields
+            .iter()
+            .all(|field| !field.required || document.contains_key(field.name.as_str()))
+    }
+
+    // Every field that is missing or breaks a constraint, with the reason.
+    pub fn violations(&self, document: &HashMap<&str, &str>) -> Vec<(&str, String)> {
+        self.fields
+            .iter()
+            .filter_map(|field| {
+                let reason = match document.get(field.name.as_str()) {
+                    Some(value) => field
+                        .constraints
+                        .iter()
+                        .find_map(|constraint| constraint.violation(value)),
+                    None if field.required => Some(String::from("missing")),
+                    None => None,
+                };
+
+                reason.map(|reason| (field.name.as_str(), reason))
+            })
+            .collect()
+    }
+
+    pub fn valid(&self, document: &HashMap<&str, &str>) -> bool {
+        self.violations(document).is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::ErrorKind;
+
+    const VEHICLE: &str = r#"{
+        "fields": {
+            "plate": { "pattern": "[A-Z]{3}-\\d{3}" },
+            "wheels": { "range": [2, 18] },
+            "fuel": { "one_of": ["diesel", "petrol"], "required": false }
+        }
+    }"#;
+
+    fn document<'a>(fields: &[(&'a str, &'a str)]) -> HashMap<&'a str, &'a str> {
+        fields.iter().cloned().collect()
+    }
+
+    #[test]
+    fn validates_new_document_types() {
+        let schema = Schema::from_json(VEHICLE).unwrap();
+
+        assert!(schema.valid(&document(&[("plate", "ABC-123"), ("wheels", "4")])));
+        assert!(!schema.complete(&document(&[("plate", "ABC-123")])));
+        assert_eq!(
+            schema.violations(&document(&[
+                ("plate", "ABC-1234"),
+                ("wheels", "1"),
+                ("fuel", "coal")
+            ])),
+            vec![
+                ("fuel", String::from("'coal' is not one of diesel, petrol")),
+                (
+                    "plate",
+                    String::from("'ABC-1234' does not match ^(?:[A-Z]{3}-\\d{3})$")
+                ),
+                ("wheels", String::from("1 is not within 2-18")),
+            ]
+        );
+    }
+
+    #[test]
+    fn checks_units() {
+        let schema = Schema::from_toml(include_str!("../schema/passport.toml")).unwrap();
+        let height = |value| {
+            schema
+                .violations(&document(&[("hgt", value)]))
+                .into_iter()
+                .find(|&(field, _)| field == "hgt")
+                .map(|(_, reason)| reason)
+        };
+
+        assert_eq!(height("60in"), None);
+        assert_eq!(
+            height("190in"),
+            Some(String::from("190 is not within 59-76"))
+        );
+        assert_eq!(
+            height("190"),
+            Some(String::from("'190' needs one of the units cm, in"))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_schemas() {
+        assert!(Schema::from_toml("[fields.byr]\nrange = [1, 2]\nregex = 'x'").is_err());
+        assert!(Schema::from_toml("[fields.byr]\npattern = '('").is_err());
+        assert!(Schema::from_json("{}").is_err());
+
+        let error = Schema::load(Path::new("missing.toml")).unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::Io);
+        assert!(error.message.starts_with("Could not read missing.toml: "));
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{BoardingPass, Geometry};
+use common::{Error, Result};
+use std::collections::BTreeMap;
+use std::ops::{Range, RangeInclusive};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    pub geometry: Geometry,
+    // How many passes were scanned for each occupied seat ID. Planes can have
+    // up to 2^31 seats, so vacancies are derived from the gaps instead.
+    passes: BTreeMap<u32, u32>,
+}
+
+impl Analysis {
+    // Larger planes are not drawn by `map`.
+    pub const MAP_SEATS: u32 = 1 << 16;
+
+    pub fn new(ids: &[u32], geometry: Geometry) -> Result<Self> {
+        let mut passes = BTreeMap::new();
+
+        for &id in ids {
+            if id >= geometry.seats() {
+                return Err(Error::invalid_argument(format!(
+                    "Seat ID {} is outside a plane with {} seats",
+                    id,
+                    geometry.seats()
+                )));
+            }
+
+            *passes.entry(id).or_insert(0) += 1;
+        }
+
+        Ok(Self { geometry, passes })
+    }
+
+    pub fn is_occupied(&self, id: u32) -> bool {
+        self.passes.contains_key(&id)
+    }
+
+    pub fn missing(&self) -> Vec<u32> {
+        self.vacant_blocks().into_iter().flatten().collect()
+    }
+
+    // Runs of consecutive seat IDs without a pass, which may span rows.
+    pub fn vacant_blocks(&self) -> Vec<RangeInclusive<u32>> {
+        let mut blocks = Vec::new();
+        let mut next = 0;
+
+        for &id in self.passes.keys() {
+            if id > next {
+                blocks.push(next..=id - 1);
+            }
+            next = id + 1;
+        }
+
+        if next < self.geometry.seats() {
+            blocks.push(next..=self.geometry.seats() - 1);
+        }
+
+        blocks
+    }
+
+    // Vacant seats with an occupied seat on both sides, like yours.
+    pub fn candidates(&self) -> Vec<u32> {
+        self.passes
+            .keys()
+            .filter(|&&id| !self.is_occupied(id + 1) && self.is_occupied(id + 2))
+            .map(|id| id + 1)
+            .collect()
+    }
+
+    // The empty rows before the first occupied one.
+    pub fn front_rows(&self) -> Range<u32> {
+        let columns = self.geometry.columns();
+
+        0..self
+            .passes
+            .keys()
+            .next()
+            .map_or(self.geometry.rows(), |id| id / columns)
+    }
+
+    // The empty rows after the last occupied one.
+    pub fn back_rows(&self) -> Range<u32> {
+        let columns = self.geometry.columns();
+        let rows = self.geometry.rows();
+
+        self.passes
+            .keys()
+            .next_back()
+            .map_or(rows, |id| id / columns + 1)..rows
+    }
+
+    pub fn duplicates(&self) -> Vec<BoardingPass> {
+        self.passes
+            .iter()
+            .filter(|&(_, &count)| count > 1)
+            .map(|(&id, _)| BoardingPass::from_seat_id(id, self.geometry).unwrap())
+            .collect()
+    }
+
+    // One line per row, with `#` for occupied seats, `.` for empty ones and
+    // `!` for seats with more than one pass. `None` above `MAP_SEATS` seats.
+    pub fn map(&self) -> Option<String> {
+        if self.geometry.seats() > Self::MAP_SEATS {
+            return None;
+        }
+
+        let columns = self.geometry.columns();
+        let width = (self.geometry.rows() - 1).to_string().len();
+        let mut map = String::new();
+
+        for row in 0..self.geometry.rows() {
+            let seats: String = (row * columns..(row + 1) * columns)
+                .map(|id| match self.passes.get(&id) {
+                    None => '.',
+                    Some(1) => '#',
+                    Some(_) => '!',
+                })
+                .collect();
+
+            map.push_str(&format!("{:>width$} {}\n", row, seats, width = width));
+        }
+
+        Some(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analysis(ids: &[u32]) -> Analysis {
+        Analysis::new(ids, Geometry::new(3, 2).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn finds_vacancies() {
+        let analysis = analysis(&[8, 9, 11, 12, 13, 18, 19, 19, 20]);
+
+        assert_eq!(analysis.candidates(), vec![10]);
+        assert_eq!(analysis.front_rows(), 0..2);
+        assert_eq!(analysis.back_rows(), 6..8);
+        assert_eq!(
+            analysis.vacant_blocks(),
+            vec![0..=7, 10..=10, 14..=17, 21..=31]
+        );
+        assert_eq!(analysis.missing().len(), 24);
+        assert_eq!(
+            analysis.duplicates(),
+            vec![BoardingPass::new(4, 3, analysis.geometry).unwrap()]
+        );
+    }
+
+    #[test]
+    fn draws_the_plane() {
+        assert_eq!(
+            analysis(&[8, 9, 11, 12, 13, 18, 19, 19, 20]).map(),
+            Some(String::from(
+                "0 ....\n1 ....\n2 ##.#\n3 ##..\n4 ..#!\n5 #...\n6 ....\n7 ....\n"
+            ))
+        );
+    }
+
+    #[test]
+    fn handles_empty_planes() {
+        let analysis = analysis(&[]);
+
+        assert_eq!(analysis.front_rows(), 0..8);
+        assert_eq!(analysis.back_rows(), 8..8);
+        assert_eq!(analysis.vacant_blocks(), vec![0..=31]);
+        assert!(Analysis::new(&[32], analysis.geometry).is_err());
+    }
+
+    #[test]
+    fn handles_large_planes() {
+        let geometry = Geometry::new(20, 11).unwrap();
+        let last = geometry.seats() - 1;
+        let analysis = Analysis::new(&[1, 3, last], geometry).unwrap();
+
+        assert_eq!(analysis.candidates(), vec![2]);
+        assert_eq!(analysis.vacant_blocks(), vec![0..=0, 2..=2, 4..=last - 1]);
+        assert_eq!(analysis.front_rows(), 0..0);
+        assert_eq!(analysis.back_rows(), geometry.rows()..geometry.rows());
+        assert_eq!(analysis.map(), None);
+    }
+}
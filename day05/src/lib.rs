@@ -0,0 +1,84 @@
+pub use analysis::Analysis;
+pub use pass::{BoardingPass, Geometry};
+
+use common::{Context, Error, Result, Solution};
+
+pub mod analysis;
+pub mod pass;
+
+pub fn seat_id(line: &str) -> Result<u32> {
+    BoardingPass::decode(line.trim(), Geometry::STANDARD).map(|pass| pass.seat_id())
+}
+
+pub fn parse(input: &str) -> Result<Vec<u32>> {
+    let mut ids: Vec<u32> = input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| seat_id(line).at_line(index + 1))
+        .collect::<Result<_>>()?;
+    ids.sort();
+    Ok(ids)
+}
+
+pub fn part1(ids: &[u32]) -> Result<u32> {
+    ids.last()
+        .copied()
+        .ok_or_else(|| Error::no_solution("No boarding passes"))
+}
+
+pub fn part2(ids: &[u32]) -> Result<u32> {
+    Analysis::new(ids, Geometry::STANDARD)?
+        .candidates()
+        .first()
+        .copied()
+        .ok_or_else(|| Error::no_solution("Seat not found"))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(ids: &Self::Input<'_>) -> Result<Self::Part1> {
+        part1(ids)
+    }
+
+    fn part2(ids: &Self::Input<'_>) -> Result<Self::Part2> {
+        part2(ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_seat_ids() {
+        assert_eq!(seat_id("FBFBBFFRLR"), Ok(357));
+        assert_eq!(seat_id("BFFFBBFRRR"), Ok(567));
+        assert_eq!(seat_id("FFFBBBFRRR"), Ok(119));
+        assert_eq!(seat_id("BBFFBBFRLL"), Ok(820));
+    }
+
+    #[test]
+    fn finds_the_missing_seat() {
+        let ids = parse(include_str!("../input/test.txt")).unwrap();
+
+        assert_eq!(part1(&ids), Ok(820));
+        assert_eq!(part2(&[10, 11, 13, 14]), Ok(12));
+    }
+
+    #[test]
+    fn reports_invalid_passes() {
+        assert_eq!(seat_id("FBFBBFFRLX").unwrap_err().column, Some(10));
+        assert!(seat_id("FBFBBFF").is_err());
+    }
+}
@@ -1,29 +1,92 @@
-use std::collections::HashSet;
-use std::io::{self, Read};
-use std::iter::FromIterator;
+use common::input;
+use common::{parse_value, Context, Error, Result};
+use day05::{Analysis, BoardingPass, Day05, Geometry};
+use std::env;
+use std::ops::Range;
+use std::process;
 
-fn main() {
-    let mut input = String::new();
-    io::stdin().read_to_string(&mut input).unwrap();
-    let mut ids = Vec::new();
-
-    for line in input.lines() {
-        let line = line.trim();
-        let row = u32::from_str_radix(&line[..7].replace("F", "0").replace("B", "1"), 2).unwrap();
-        let column =
-            u32::from_str_radix(&line[7..].replace("L", "0").replace("R", "1"), 2).unwrap();
+fn rows(rows: Range<u32>) -> String {
+    match rows.len() {
+        0 => String::from("none"),
+        _ => format!("{}-{}", rows.start, rows.end - 1),
+    }
+}
 
-        ids.push((row * 8) + column);
+fn list(items: Vec<String>) -> String {
+    match items.is_empty() {
+        true => String::from("none"),
+        false => items.join(", "),
     }
+}
 
-    ids.sort();
-    println!("{:?}", ids.last());
+// `day05 analyze [<row bits> <column bits>]` reports the vacant seats for the
+// boarding passes on stdin and draws the plane.
+fn run_analyze(args: &[String]) -> Result<String> {
+    let geometry = match args {
+        [] => Geometry::STANDARD,
+        [row_bits, column_bits] => {
+            Geometry::new(parse_value(row_bits)?, parse_value(column_bits)?)?
+        }
+        _ => {
+            return Err(Error::invalid_argument(
+                "Usage: day05 analyze [<row bits> <column bits>]",
+            ))
+        }
+    };
+    let input = input::stdin()?;
+    let ids = input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            BoardingPass::decode(line.trim(), geometry)
+                .map(|pass| pass.seat_id())
+                .at_line(index + 1)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let analysis = Analysis::new(&ids, geometry)?;
+    let blocks = analysis
+        .vacant_blocks()
+        .iter()
+        .map(|block| match block.start() == block.end() {
+            true => block.start().to_string(),
+            false => format!("{}-{}", block.start(), block.end()),
+        })
+        .collect::<Vec<_>>();
+    let duplicates = analysis
+        .duplicates()
+        .iter()
+        .map(|pass| format!("{} ({})", pass, pass.seat_id()))
+        .collect::<Vec<_>>();
+    let candidates = analysis
+        .candidates()
+        .iter()
+        .map(u32::to_string)
+        .collect::<Vec<_>>();
 
-    let set: HashSet<&u32> = HashSet::from_iter(ids.iter());
+    Ok(format!(
+        "{}\nmissing front rows: {}\nmissing back rows: {}\nvacant seats: {}\nduplicates: {}\ncandidates: {}",
+        analysis
+            .map()
+            .unwrap_or_else(|| String::from("(plane too large to draw)\n")),
+        rows(analysis.front_rows()),
+        rows(analysis.back_rows()),
+        list(blocks),
+        list(duplicates),
+        list(candidates)
+    ))
+}
 
-    for id in ids[0]..ids.last().unwrap().to_owned() {
-        if !set.contains(&(id + 1)) && set.contains(&(id + 2)) {
-            println!("{:?}", id + 1);
-        }
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.split_first() {
+        Some((command, args)) if command == "analyze" => match run_analyze(args) {
+            Ok(report) => println!("{}", report),
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+        },
+        _ => common::main::<Day05>(),
     }
 }
@@ -0,0 +1,192 @@
+use common::{Error, Result};
+use std::fmt;
+
+// How many bits of a boarding pass pick the row and the column. Each half
+// is a binary number, with F and L as 0 and B and R as 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Geometry {
+    row_bits: u32,
+    column_bits: u32,
+}
+
+impl Geometry {
+    pub const STANDARD: Geometry = Geometry {
+        row_bits: 7,
+        column_bits: 3,
+    };
+
+    pub fn new(row_bits: u32, column_bits: u32) -> Result<Self> {
+        match row_bits.checked_add(column_bits) {
+            Some(1..=31) => Ok(Self {
+                row_bits,
+                column_bits,
+            }),
+            _ => Err(Error::invalid_argument(
+                "A boarding pass needs between 1 and 31 bits",
+            )),
+        }
+    }
+
+    pub fn row_bits(&self) -> u32 {
+        self.row_bits
+    }
+
+    pub fn column_bits(&self) -> u32 {
+        self.column_bits
+    }
+
+    pub fn rows(&self) -> u32 {
+        1 << self.row_bits
+    }
+
+    pub fn columns(&self) -> u32 {
+        1 << self.column_bits
+    }
+
+    pub fn seats(&self) -> u32 {
+        self.rows() * self.columns()
+    }
+
+    pub fn code_length(&self) -> usize {
+        (self.row_bits + self.column_bits) as usize
+    }
+}
+
+impl Default for Geometry {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoardingPass {
+    pub row: u32,
+    pub column: u32,
+    pub geometry: Geometry,
+}
+
+impl BoardingPass {
+    pub fn new(row: u32, column: u32, geometry: Geometry) -> Result<Self> {
+        match row < geometry.rows() && column < geometry.columns() {
+            true => Ok(Self {
+                row,
+                column,
+                geometry,
+            }),
+            false => Err(Error::invalid_argument(format!(
+                "Seat at row {}, column {} is outside a {}x{} plane",
+                row,
+                column,
+                geometry.rows(),
+                geometry.columns()
+            ))),
+        }
+    }
+
+    pub fn from_seat_id(id: u32, geometry: Geometry) -> Result<Self> {
+        match id < geometry.seats() {
+            true => Self::new(id / geometry.columns(), id % geometry.columns(), geometry),
+            false => Err(Error::invalid_argument(format!(
+                "Seat ID {} is outside a plane with {} seats",
+                id,
+                geometry.seats()
+            ))),
+        }
+    }
+
+    pub fn decode(code: &str, geometry: Geometry) -> Result<Self> {
+        if code.len() != geometry.code_length() || !code.is_ascii() {
+            return Err(Error::parse(format!(
+                "Expected {} characters, found '{}'",
+                geometry.code_length(),
+                code
+            )));
+        }
+
+        let mut id = 0;
+
+        for (index, character) in code.chars().enumerate() {
+            let bit = match (index < geometry.row_bits as usize, character) {
+                (true, 'F') | (false, 'L') => 0,
+                (true, 'B') | (false, 'R') => 1,
+                _ => {
+                    return Err(Error::parse(format!("Invalid character '{}'", character))
+                        .at_column(index + 1))
+                }
+            };
+
+            id = (id << 1) | bit;
+        }
+
+        Self::from_seat_id(id, geometry)
+    }
+
+    pub fn encode(&self) -> String {
+        let bits = |value: u32, count: u32, zero: char, one: char| {
+            (0..count).rev().map(move |bit| match (value >> bit) & 1 {
+                0 => zero,
+                _ => one,
+            })
+        };
+
+        bits(self.row, self.geometry.row_bits, 'F', 'B')
+            .chain(bits(self.column, self.geometry.column_bits, 'L', 'R'))
+            .collect()
+    }
+
+    pub fn seat_id(&self) -> u32 {
+        self.row * self.geometry.columns() + self.column
+    }
+}
+
+impl fmt::Display for BoardingPass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.encode())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_rows_and_columns() {
+        let pass = BoardingPass::decode("FBFBBFFRLR", Geometry::STANDARD).unwrap();
+
+        assert_eq!((pass.row, pass.column, pass.seat_id()), (44, 5, 357));
+    }
+
+    #[test]
+    fn round_trips_any_geometry() {
+        let geometry = Geometry::new(4, 2).unwrap();
+
+        for id in 0..geometry.seats() {
+            let pass = BoardingPass::from_seat_id(id, geometry).unwrap();
+
+            assert_eq!(pass.encode().len(), 6);
+            assert_eq!(BoardingPass::decode(&pass.encode(), geometry), Ok(pass));
+            assert_eq!(pass.seat_id(), id);
+        }
+
+        assert_eq!(
+            BoardingPass::new(9, 3, geometry).unwrap().to_string(),
+            "BFFBRR"
+        );
+    }
+
+    #[test]
+    fn rejects_out_of_range_seats() {
+        let geometry = Geometry::new(4, 2).unwrap();
+
+        assert!(BoardingPass::from_seat_id(64, geometry).is_err());
+        assert!(BoardingPass::new(0, 4, geometry).is_err());
+        assert!(BoardingPass::decode("FBFBBFFRLR", geometry).is_err());
+        assert_eq!(
+            BoardingPass::decode("BFFBLF", geometry).unwrap_err().column,
+            Some(6)
+        );
+        assert!(Geometry::new(0, 0).is_err());
+        assert!(Geometry::new(30, 2).is_err());
+        assert!(Geometry::new(u32::MAX, 1).is_err());
+    }
+}
@@ -0,0 +1,218 @@
+use common::input::{groups, lines};
+use common::{parse_value, Error, Result};
+use std::str::FromStr;
+
+// The questions a form can ask, each mapped to a bit of an answer set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alphabet {
+    questions: Vec<char>,
+}
+
+impl Alphabet {
+    pub fn new(questions: &str) -> Result<Self> {
+        let questions: Vec<char> = questions.chars().collect();
+
+        if questions.is_empty() || questions.len() > 128 {
+            return Err(Error::invalid_argument(
+                "An alphabet needs between 1 and 128 questions",
+            ));
+        }
+
+        match questions
+            .iter()
+            .enumerate()
+            .find(|&(index, question)| questions[..index].contains(question))
+        {
+            Some((_, question)) => Err(Error::invalid_argument(format!(
+                "Question '{}' appears twice in the alphabet",
+                question
+            ))),
+            None => Ok(Self { questions }),
+        }
+    }
+
+    pub fn lowercase() -> Self {
+        Self {
+            questions: ('a'..='z').collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.questions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.questions.is_empty()
+    }
+
+    pub fn index(&self, question: char) -> Option<usize> {
+        self.questions.iter().position(|&q| q == question)
+    }
+
+    pub fn question(&self, index: usize) -> char {
+        self.questions[index]
+    }
+}
+
+impl Default for Alphabet {
+    fn default() -> Self {
+        Self::lowercase()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Any,
+    All,
+    AtLeast(usize),
+    Majority,
+}
+
+// Accepts `any`, `all`, `majority` or `at-least:K`.
+impl FromStr for Mode {
+    type Err = Error;
+
+    fn from_str(mode: &str) -> Result<Self> {
+        match mode {
+            "any" => Ok(Mode::Any),
+            "all" => Ok(Mode::All),
+            "majority" => Ok(Mode::Majority),
+            _ => match mode.strip_prefix("at-least:") {
+                Some(k) => Ok(Mode::AtLeast(parse_value(k)?)),
+                None => Err(Error::invalid_argument(format!(
+                    "Unknown mode '{}', expected any, all, majority or at-least:K",
+                    mode
+                ))),
+            },
+        }
+    }
+}
+
+// One answer set per person, as bitsets over an alphabet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    pub questions: usize,
+    pub people: Vec<u128>,
+}
+
+impl Group {
+    pub fn size(&self) -> usize {
+        self.people.len()
+    }
+
+    // How many people answered yes to each question of the alphabet.
+    pub fn counts(&self) -> Vec<usize> {
+        (0..self.questions)
+            .map(|bit| {
+                self.people
+                    .iter()
+                    .filter(|&&person| person & (1 << bit) != 0)
+                    .count()
+            })
+            .collect()
+    }
+
+    fn at_least(&self, k: usize) -> u128 {
+        self.counts()
+            .iter()
+            .enumerate()
+            .filter(|&(_, &count)| count >= k)
+            .fold(0, |answers, (bit, _)| answers | (1 << bit))
+    }
+
+    // The questions the group answered yes to under the given mode.
+    pub fn aggregate(&self, mode: Mode) -> u128 {
+        match mode {
+            Mode::Any => self
+                .people
+                .iter()
+                .fold(0, |answers, person| answers | person),
+            Mode::All => self
+                .people
+                .iter()
+                .fold(u128::MAX, |answers, person| answers & person),
+            Mode::AtLeast(k) => self.at_least(k),
+            Mode::Majority => self.at_least(self.size() / 2 + 1),
+        }
+    }
+
+    pub fn count(&self, mode: Mode) -> usize {
+        self.aggregate(mode).count_ones() as usize
+    }
+}
+
+fn person(line: &str, row: usize, alphabet: &Alphabet) -> Result<u128> {
+    line.chars()
+        .enumerate()
+        .try_fold(0, |answers, (index, c)| match alphabet.index(c) {
+            Some(bit) => Ok(answers | (1 << bit)),
+            None => Err(Error::parse(format!("Invalid answer '{}'", c))
+                .at_line(row + 1)
+                .at_column(index + 1)),
+        })
+}
+
+pub fn parse_groups<'a>(
+    input: &'a str,
+    alphabet: &'a Alphabet,
+) -> impl Iterator<Item = Result<Group>> + 'a {
+    groups(input).map(move |(first_line, group)| {
+        Ok(Group {
+            questions: alphabet.len(),
+            people: lines(group)
+                .enumerate()
+                .map(|(index, line)| person(line, first_line - 1 + index, alphabet))
+                .collect::<Result<_>>()?,
+        })
+    })
+}
+
+pub fn count(input: &str, alphabet: &Alphabet, mode: Mode) -> Result<usize> {
+    parse_groups(input, alphabet).try_fold(0, |count, group| Ok(count + group?.count(mode)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(people: &[&str]) -> Group {
+        parse_groups(&people.join("\n"), &Alphabet::lowercase())
+            .next()
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn aggregates_with_quorums() {
+        let group = group(&["abc", "ab", "a", "bd"]);
+
+        assert_eq!(group.size(), 4);
+        assert_eq!(&group.counts()[..5], &[3, 3, 1, 1, 0]);
+        assert_eq!(group.count(Mode::Any), 4);
+        assert_eq!(group.count(Mode::All), 0);
+        assert_eq!(group.aggregate(Mode::AtLeast(2)), 0b11);
+        assert_eq!(group.count(Mode::Majority), 2);
+        assert_eq!(group.count(Mode::AtLeast(1)), group.count(Mode::Any));
+    }
+
+    #[test]
+    fn supports_other_alphabets() {
+        let alphabet = Alphabet::new("αβγ0123456789").unwrap();
+
+        assert_eq!(count("αβ\nβ7\n\nγ\n", &alphabet, Mode::Any), Ok(4));
+        assert_eq!(count("αβ\nβ7\n\nγ\n", &alphabet, Mode::All), Ok(2));
+        assert_eq!(
+            count("αβ\na\n", &alphabet, Mode::Any).unwrap_err().line,
+            Some(2)
+        );
+        assert!(Alphabet::new("abca").is_err());
+        assert!(Alphabet::new(&"x".repeat(129)).is_err());
+    }
+
+    #[test]
+    fn parses_modes() {
+        assert_eq!("majority".parse(), Ok(Mode::Majority));
+        assert_eq!("at-least:3".parse(), Ok(Mode::AtLeast(3)));
+        assert!("most".parse::<Mode>().is_err());
+    }
+}
@@ -0,0 +1,69 @@
+pub use customs::{count, parse_groups, Alphabet, Group, Mode};
+pub use report::Report;
+
+use common::{Result, Solution};
+
+pub mod customs;
+pub mod report;
+
+pub fn parse(input: &str) -> Result<Vec<Group>> {
+    parse_groups(input, &Alphabet::lowercase()).collect()
+}
+
+pub fn part1(groups: &[Group]) -> usize {
+    groups.iter().map(|group| group.count(Mode::Any)).sum()
+}
+
+pub fn part2(groups: &[Group]) -> usize {
+    groups.iter().map(|group| group.count(Mode::All)).sum()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = Vec<Group>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(groups: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(part1(groups))
+    }
+
+    fn part2(groups: &Self::Input<'_>) -> Result<Self::Part2> {
+        Ok(part2(groups))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.txt");
+
+    #[test]
+    fn example() {
+        let example = Day06::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day06::part1(&example), Ok(11));
+        assert_eq!(Day06::part2(&example), Ok(6));
+        assert_eq!(parse(EXAMPLE.trim_end()).map(|g| part2(&g)), Ok(6));
+        assert_eq!(
+            parse(&EXAMPLE.replace('\n', " \r\n")).map(|g| part2(&g)),
+            Ok(6)
+        );
+    }
+
+    #[test]
+    fn reports_invalid_answers() {
+        let error = parse("abc\n\naB\n").unwrap_err();
+
+        assert_eq!(error.line, Some(3));
+        assert_eq!(error.column, Some(2));
+    }
+}
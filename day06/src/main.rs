@@ -1,55 +1,70 @@
-use std::collections::HashSet;
-use std::hash::Hash;
-use std::io::{self, Read};
-use std::iter::FromIterator;
+use common::input;
+use common::{Error, Result};
+use day06::{count, Alphabet, Day06, Report};
+use std::env;
+use std::process;
 
-fn part1(input: &str) -> usize {
-    let mut answers: HashSet<char> = HashSet::new();
-    let mut count: usize = 0;
-
-    for line in input.lines() {
-        if line.is_empty() {
-            count += answers.len();
-            answers = HashSet::new();
-
-            continue;
-        }
-
-        for c in line.chars().into_iter() {
-            answers.insert(c);
+// `day06 count <mode> [<alphabet>]` sums the questions each group on stdin
+// answered yes to, where mode is any, all, majority or at-least:K.
+fn run_count(args: &[String]) -> Result<usize> {
+    let (mode, alphabet) = match args {
+        [mode] => (mode.parse()?, Alphabet::lowercase()),
+        [mode, alphabet] => (mode.parse()?, Alphabet::new(alphabet)?),
+        _ => {
+            return Err(Error::invalid_argument(
+                "Usage: day06 count <mode> [<alphabet>]",
+            ))
         }
-    }
-
-    count
-}
+    };
+    let input = input::stdin()?;
 
-fn intersection<T: Eq + Hash>(a: HashSet<T>, b: &HashSet<T>) -> HashSet<T> {
-    a.into_iter().filter(|e| b.contains(e)).collect()
+    count(&input, &alphabet, mode)
 }
 
-fn part2(input: &str) -> usize {
-    let mut answers: HashSet<char> = HashSet::from_iter(('a'..='z').into_iter());
-    let mut count: usize = 0;
-
-    for line in input.lines() {
-        if line.is_empty() {
-            count += answers.len();
-            answers = HashSet::from_iter(('a'..='z').into_iter());
-
-            continue;
+// `day06 report <csv|sizes-csv|json> [<alphabet>]` summarises every question
+// across the groups on stdin.
+fn run_report(args: &[String]) -> Result<String> {
+    let (format, alphabet) = match args {
+        [format] => (format, Alphabet::lowercase()),
+        [format, alphabet] => (format, Alphabet::new(alphabet)?),
+        _ => {
+            return Err(Error::invalid_argument(
+                "Usage: day06 report <csv|sizes-csv|json> [<alphabet>]",
+            ))
         }
+    };
+    let input = input::stdin()?;
+    let report = Report::from_input(&input, &alphabet)?;
 
-        let individual: HashSet<char> = HashSet::from_iter(line.chars().into_iter());
-        answers = intersection(answers, &individual);
+    match format.as_str() {
+        "csv" => Ok(report.to_csv()),
+        "sizes-csv" => Ok(report.sizes_to_csv()),
+        "json" => Ok(report.to_json()),
+        _ => Err(Error::invalid_argument(format!(
+            "Unknown format '{}', expected csv, sizes-csv or json",
+            format
+        ))),
     }
-
-    count
 }
 
 fn main() {
-    let mut input = String::new();
-    io::stdin().read_to_string(&mut input).unwrap();
-    input.push('\n');
-    println!("{:?}", part1(&input));
-    println!("{:?}", part2(&input));
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.split_first() {
+        Some((command, args)) if command == "count" => match run_count(args) {
+            Ok(count) => println!("{}", count),
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+        },
+        Some((command, args)) if command == "report" => match run_report(args) {
+            Ok(report) => print!("{}", report),
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+        },
+        _ => common::main::<Day06>(),
+    }
 }
@@ -0,0 +1,160 @@
+use crate::{parse_groups, Alphabet, Group, Mode};
+use common::Result;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Question {
+    pub question: char,
+    // Groups where anyone answered yes, and where everyone did.
+    pub groups: usize,
+    pub unanimous: usize,
+    pub individuals: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GroupSize {
+    pub people: usize,
+    pub groups: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub groups: usize,
+    pub individuals: usize,
+    pub questions: Vec<Question>,
+    pub group_sizes: Vec<GroupSize>,
+}
+
+impl Report {
+    pub fn new<I: IntoIterator<Item = Group>>(groups: I, alphabet: &Alphabet) -> Self {
+        let mut questions: Vec<Question> = (0..alphabet.len())
+            .map(|index| Question {
+                question: alphabet.question(index),
+                groups: 0,
+                unanimous: 0,
+                individuals: 0,
+            })
+            .collect();
+        let mut sizes = BTreeMap::new();
+        let mut count = 0;
+
+        for group in groups {
+            let (any, all) = (group.aggregate(Mode::Any), group.aggregate(Mode::All));
+
+            for (bit, (question, yes)) in questions.iter_mut().zip(group.counts()).enumerate() {
+                question.groups += (any >> bit & 1) as usize;
+                question.unanimous += (all >> bit & 1) as usize;
+                question.individuals += yes;
+            }
+
+            *sizes.entry(group.size()).or_insert(0) += 1;
+            count += 1;
+        }
+
+        Self {
+            groups: count,
+            individuals: sizes.iter().map(|(people, groups)| people * groups).sum(),
+            questions,
+            group_sizes: sizes
+                .into_iter()
+                .map(|(people, groups)| GroupSize { people, groups })
+                .collect(),
+        }
+    }
+
+    pub fn from_input(input: &str, alphabet: &Alphabet) -> Result<Self> {
+        let groups = parse_groups(input, alphabet).collect::<Result<Vec<_>>>()?;
+
+        Ok(Self::new(groups, alphabet))
+    }
+
+    // The per-question table. Questions are single characters, so only a
+    // comma or a quote needs quoting.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("question,groups,unanimous,individuals\n");
+
+        for question in &self.questions {
+            let name = match question.question {
+                '"' => String::from("\"\"\"\""),
+                ',' => String::from("\",\""),
+                other => other.to_string(),
+            };
+
+            csv.push_str(&format!(
+                "{},{},{},{}\n",
+                name, question.groups, question.unanimous, question.individuals
+            ));
+        }
+
+        csv
+    }
+
+    pub fn sizes_to_csv(&self) -> String {
+        let mut csv = String::from("people,groups\n");
+
+        for size in &self.group_sizes {
+            csv.push_str(&format!("{},{}\n", size.people, size.groups));
+        }
+
+        csv
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> Report {
+        Report::from_input(
+            include_str!("../input/test.txt"),
+            &Alphabet::new("abc").unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn counts_each_question() {
+        let report = report();
+
+        assert_eq!((report.groups, report.individuals), (5, 11));
+        assert_eq!(
+            report.questions[0],
+            Question {
+                question: 'a',
+                groups: 4,
+                unanimous: 3,
+                individuals: 8
+            }
+        );
+        assert_eq!(
+            report.to_csv(),
+            "question,groups,unanimous,individuals\na,4,3,8\nb,4,2,4\nc,3,1,3\n"
+        );
+        assert_eq!(report.sizes_to_csv(), "people,groups\n1,2\n2,1\n3,1\n4,1\n");
+    }
+
+    #[test]
+    fn exports_json() {
+        let json: serde_json::Value = serde_json::from_str(&report().to_json()).unwrap();
+
+        assert_eq!(json["questions"][2]["question"], "c");
+        assert_eq!(json["questions"][2]["individuals"], 3);
+        assert_eq!(json["group_sizes"][0]["groups"], 2);
+    }
+
+    #[test]
+    fn quotes_csv_fields() {
+        let alphabet = Alphabet::new(",\"").unwrap();
+        let report = Report::from_input(",\n\"", &alphabet).unwrap();
+
+        assert_eq!(
+            report.to_csv(),
+            "question,groups,unanimous,individuals\n\",\",1,0,1\n\"\"\"\",1,0,1\n"
+        );
+    }
+}
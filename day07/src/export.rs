@@ -0,0 +1,132 @@
+use crate::query::{find_cycle, node};
+use common::{Error, Result};
+use petgraph::graphmap::GraphMap;
+use petgraph::Directed;
+
+// A Graphviz digraph with an edge from each bag to the bags it holds,
+// labelled with how many, and `goal` filled in.
+pub fn to_dot(graph: &GraphMap<&str, u32, Directed>, goal: Option<&str>) -> String {
+    let mut dot = String::from("digraph bags {\n");
+
+    for bag in graph.nodes() {
+        match Some(bag) == goal {
+            true => dot.push_str(&format!(
+                "    \"{}\" [style=filled, fillcolor=gold];\n",
+                bag
+            )),
+            false => dot.push_str(&format!("    \"{}\";\n", bag)),
+        }
+    }
+
+    for (outer, inner, amount) in graph.all_edges() {
+        dot.push_str(&format!(
+            "    \"{}\" -> \"{}\" [label=\"{}\"];\n",
+            outer, inner, amount
+        ));
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+fn branch(
+    graph: &GraphMap<&str, u32, Directed>,
+    bag: &str,
+    amount: u64,
+    depth: usize,
+    tree: &mut String,
+) -> Result<u64> {
+    let mut inside: u64 = 0;
+
+    for (_, inner, &each) in graph.edges(bag) {
+        let overflow = || Error::no_solution(format!("Too many '{}' bags to list", inner));
+        let total = amount.checked_mul(each as u64).ok_or_else(overflow)?;
+
+        tree.push_str(&format!("{}{} {}\n", "  ".repeat(depth), total, inner));
+        inside = branch(graph, inner, total, depth + 1, tree)?
+            .checked_add(total)
+            .and_then(|bags| bags.checked_add(inside))
+            .ok_or_else(overflow)?;
+    }
+
+    Ok(inside)
+}
+
+// Everything `bag` holds, one bag per line and indented by depth. Counts are
+// cumulative: how many of that bag there are in total at that spot.
+pub fn to_tree(graph: &GraphMap<&str, u32, Directed>, bag: &str) -> Result<String> {
+    let bag = node(graph, bag)?;
+
+    if let Some(cycle) = find_cycle(graph) {
+        return Err(Error::invalid_argument(format!(
+            "Bags contain each other: {}",
+            cycle.join(" -> ")
+        )));
+    }
+
+    let mut tree = String::new();
+    let inside = branch(graph, bag, 1, 1, &mut tree)?;
+
+    Ok(format!("{} holds {} bags\n{}", bag, inside, tree))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, GOAL};
+
+    const EXAMPLE: &str = include_str!("../input/test.txt");
+
+    #[test]
+    fn exports_dot() {
+        let graph = parse(EXAMPLE.lines().skip(4)).unwrap();
+
+        assert_eq!(
+            to_dot(&graph, Some(GOAL)),
+            "digraph bags {
+    \"shiny gold\" [style=filled, fillcolor=gold];
+    \"dark olive\";
+    \"vibrant plum\";
+    \"faded blue\";
+    \"dotted black\";
+    \"shiny gold\" -> \"dark olive\" [label=\"1\"];
+    \"shiny gold\" -> \"vibrant plum\" [label=\"2\"];
+    \"dark olive\" -> \"faded blue\" [label=\"3\"];
+    \"dark olive\" -> \"dotted black\" [label=\"4\"];
+    \"vibrant plum\" -> \"faded blue\" [label=\"5\"];
+    \"vibrant plum\" -> \"dotted black\" [label=\"6\"];
+}
+"
+        );
+    }
+
+    #[test]
+    fn prints_nested_contents() {
+        let graph = parse(EXAMPLE.lines()).unwrap();
+
+        assert_eq!(
+            to_tree(&graph, GOAL).unwrap(),
+            "shiny gold holds 32 bags
+  1 dark olive
+    3 faded blue
+    4 dotted black
+  2 vibrant plum
+    10 faded blue
+    12 dotted black
+"
+        );
+        assert_eq!(
+            to_tree(&graph, "faded blue").unwrap(),
+            "faded blue holds 0 bags\n"
+        );
+        assert!(to_tree(&graph, "mirrored teal").is_err());
+
+        let huge = "light red bags contain 4294967295 bright white bags.
+bright white bags contain 4294967295 faded blue bags.
+faded blue bags contain 1 dotted black bag.
+dotted black bags contain no other bags.";
+        let error = to_tree(&parse(huge.lines()).unwrap(), "light red").unwrap_err();
+
+        assert_eq!(error.kind, common::ErrorKind::NoSolution);
+    }
+}
@@ -0,0 +1,210 @@
+pub use export::{to_dot, to_tree};
+pub use query::{containers, contents, deepest, find_cycle, paths};
+
+use common::{column_of, parse_value, Context, Error, Result, Solution};
+use petgraph::graphmap::GraphMap;
+use petgraph::Directed;
+use regex::Regex;
+use std::collections::HashMap;
+
+#[macro_use]
+extern crate lazy_static;
+
+pub mod export;
+pub mod query;
+
+pub const GOAL: &str = "shiny gold";
+
+lazy_static! {
+    static ref LINE_RE: Regex = Regex::new(r"(\w+ \w+) bags contain (.*)").unwrap();
+    static ref ITEM_RE: Regex = Regex::new(r"(\d+) (\w+ \w+) bags?").unwrap();
+}
+
+// A bag mentioned inside another, with its 1-based line and column.
+struct Reference<'a> {
+    bag: &'a str,
+    line: usize,
+    column: usize,
+}
+
+struct Rules<'a> {
+    graph: GraphMap<&'a str, u32, Directed>,
+    definitions: Vec<(&'a str, usize)>,
+    references: Vec<Reference<'a>>,
+}
+
+fn read<'a, I>(lines: I) -> Result<Rules<'a>>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut rules = Rules {
+        graph: GraphMap::new(),
+        definitions: Vec::new(),
+        references: Vec::new(),
+    };
+
+    for (row, line) in lines.into_iter().enumerate() {
+        let (item, items) = LINE_RE
+            .captures(line)
+            .and_then(|captures| Some((captures.get(1)?.as_str(), captures.get(2)?.as_str())))
+            .ok_or_else(|| {
+                Error::parse(format!(
+                    "Expected '<bag> bags contain <contents>', found '{}'",
+                    line
+                ))
+                .at_line(row + 1)
+            })?;
+
+        rules.graph.add_node(item);
+        rules.definitions.push((item, row + 1));
+
+        for captures in ITEM_RE.captures_iter(items) {
+            let amount = captures.get(1).unwrap().as_str();
+            let amount = parse_value::<u32>(amount)
+                .at_line(row + 1)
+                .at_column(column_of(line, amount))?;
+            let inner = captures.get(2).unwrap().as_str();
+
+            rules.graph.add_edge(item, inner, amount);
+            rules.references.push(Reference {
+                bag: inner,
+                line: row + 1,
+                column: column_of(line, inner),
+            });
+        }
+    }
+
+    Ok(rules)
+}
+
+pub fn parse<'a, I>(lines: I) -> Result<GraphMap<&'a str, u32, Directed>>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    Ok(read(lines)?.graph)
+}
+
+// Like `parse`, but rejects rule sets where a bag is defined twice, never
+// defined, or ends up inside itself, so every query on the graph terminates.
+pub fn validate<'a, I>(lines: I) -> Result<GraphMap<&'a str, u32, Directed>>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let rules = read(lines)?;
+    let mut defined: HashMap<&str, usize> = HashMap::new();
+
+    for &(bag, line) in &rules.definitions {
+        if let Some(first) = defined.insert(bag, line) {
+            return Err(Error::parse(format!(
+                "Bag '{}' is already defined on line {}",
+                bag, first
+            ))
+            .at_line(line));
+        }
+    }
+
+    if let Some(reference) = rules
+        .references
+        .iter()
+        .find(|reference| !defined.contains_key(reference.bag))
+    {
+        return Err(
+            Error::parse(format!("Bag '{}' is never defined", reference.bag))
+                .at_line(reference.line)
+                .at_column(reference.column),
+        );
+    }
+
+    if let Some(cycle) = find_cycle(&rules.graph) {
+        return Err(
+            Error::parse(format!("Bags contain each other: {}", cycle.join(" -> ")))
+                .at_line(defined[cycle[0]]),
+        );
+    }
+
+    Ok(rules.graph)
+}
+
+pub fn part1(graph: &GraphMap<&str, u32, Directed>) -> Result<usize> {
+    Ok(containers(graph, GOAL)?.len())
+}
+
+pub fn part2(graph: &GraphMap<&str, u32, Directed>) -> Result<u32> {
+    contents(graph, GOAL)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = GraphMap<&'a str, u32, Directed>;
+    type Part1 = usize;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        validate(input.lines())
+    }
+
+    fn part1(graph: &Self::Input<'_>) -> Result<Self::Part1> {
+        part1(graph)
+    }
+
+    fn part2(graph: &Self::Input<'_>) -> Result<Self::Part2> {
+        part2(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.txt");
+    const EXAMPLE_2: &str = include_str!("../input/test2.txt");
+
+    #[test]
+    fn example() {
+        let graph = parse(EXAMPLE.lines()).unwrap();
+
+        assert_eq!(part1(&graph), Ok(4));
+        assert_eq!(part2(&graph), Ok(32));
+    }
+
+    #[test]
+    fn nested_example() {
+        let graph = parse(EXAMPLE_2.lines()).unwrap();
+
+        assert_eq!(part2(&graph), Ok(126));
+    }
+
+    #[test]
+    fn reports_invalid_rules() {
+        assert_eq!(parse("light red bags".lines()).unwrap_err().line, Some(1));
+
+        let undefined = "light red bags contain 1 bright white bag, 2 muted yellow bags.
+bright white bags contain no other bags.";
+        let error = validate(undefined.lines()).unwrap_err();
+
+        assert_eq!(error.message, "Bag 'muted yellow' is never defined");
+        assert_eq!((error.line, error.column), (Some(1), Some(46)));
+
+        let cycle = "light red bags contain 1 bright white bag.
+bright white bags contain 2 muted yellow bags.
+muted yellow bags contain 1 bright white bag.";
+        let error = validate(cycle.lines()).unwrap_err();
+
+        assert!(error.message.starts_with("Bags contain each other: "));
+        assert!(error.message.contains("bright white -> muted yellow"));
+        assert!(validate(
+            cycle
+                .lines()
+                .take(2)
+                .chain(Some("muted yellow bags contain no other bags."))
+        )
+        .is_ok());
+
+        let twice = "light red bags contain no other bags.\nlight red bags contain no other bags.";
+
+        assert_eq!(validate(twice.lines()).unwrap_err().line, Some(2));
+    }
+}
@@ -1,62 +1,52 @@
-use petgraph::Directed;
-use petgraph::{algo::has_path_connecting, graphmap::GraphMap};
-use regex::Regex;
-use std::io::{self, Read};
-
-#[macro_use]
-extern crate lazy_static;
-
-const GOAL: &str = "shiny gold";
-
-lazy_static! {
-    static ref LINE_RE: Regex = Regex::new(r"(\w+ \w+) bags contain (.*)").unwrap();
-    static ref ITEM_RE: Regex = Regex::new(r"(\d+) (\w+ \w+) bags?").unwrap();
-}
-
-pub fn parse<'a, I>(lines: I) -> GraphMap<&'a str, u32, Directed>
-where
-    I: IntoIterator<Item = &'a str>,
-{
-    let mut graph: GraphMap<&str, u32, Directed> = GraphMap::new();
-
-    for line in lines {
-        if let Some((item, items)) = LINE_RE
-            .captures(&line)
-            .and_then(|captures| Some((captures.get(1)?.as_str(), captures.get(2)?.as_str())))
-        {
-            ITEM_RE.captures_iter(items).for_each(|captures| {
-                graph.add_edge(
-                    item,
-                    captures.get(2).unwrap().as_str(),
-                    captures.get(1).unwrap().as_str().parse::<u32>().unwrap(),
-                );
-            });
-        }
+use common::input;
+use common::{Error, Result};
+use day07::{containers, contents, deepest, paths, to_dot, to_tree, validate, Day07, GOAL};
+use std::env;
+use std::process;
+
+const USAGE: &str = "Usage: day07 <query> with the rules on stdin, where query is one of
+  containers <bag>        bags that can eventually hold <bag>
+  contents <bag>          how many bags <bag> holds
+  paths <outer> <inner>   every way <outer> can hold <inner>
+  deepest [<bag>]         the longest chain of nested bags
+  dot [<bag>]             the rules as a Graphviz graph, with <bag> filled in
+  tree <bag>              everything <bag> holds, nested";
+
+fn run_query(args: &[String]) -> Result<String> {
+    let input = input::stdin()?;
+    let graph = validate(input.lines())?;
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    match args.as_slice() {
+        ["containers", bag] => Ok(containers(&graph, bag)?.join("\n")),
+        ["contents", bag] => Ok(contents(&graph, bag)?.to_string()),
+        ["paths", outer, inner] => Ok(paths(&graph, outer, inner)?
+            .iter()
+            .map(|path| path.join(" -> "))
+            .collect::<Vec<_>>()
+            .join("\n")),
+        ["deepest"] => Ok(deepest(&graph, None)?.join(" -> ")),
+        ["deepest", bag] => Ok(deepest(&graph, Some(bag))?.join(" -> ")),
+        ["dot"] => Ok(to_dot(&graph, Some(GOAL))),
+        ["dot", bag] => Ok(to_dot(&graph, Some(bag))),
+        ["tree", bag] => Ok(to_tree(&graph, bag)?),
+        _ => Err(Error::invalid_argument(USAGE)),
     }
-
-    graph
-}
-
-fn part1(graph: &GraphMap<&str, u32, Directed>) -> usize {
-    let count = graph
-        .nodes()
-        .filter(|&node| node != GOAL && has_path_connecting(&graph, node, GOAL, None))
-        .count();
-
-    count
-}
-
-fn part2(graph: &GraphMap<&str, u32, Directed>, node: &str) -> u32 {
-    graph
-        .edges(node)
-        .map(|(_outer, inner, count)| part2(graph, inner) * count + count)
-        .sum()
 }
 
 fn main() {
-    let mut input = String::new();
-    io::stdin().read_to_string(&mut input).unwrap();
-    let graph = parse(input.lines());
-    println!("{:?}", part1(&graph));
-    println!("{:?}", part2(&graph, GOAL));
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("containers" | "contents" | "paths" | "deepest" | "dot" | "tree") => {
+            match run_query(&args) {
+                Ok(answer) => println!("{}", answer.trim_end()),
+                Err(error) => {
+                    eprintln!("{}", error);
+                    process::exit(1);
+                }
+            }
+        }
+        _ => common::main::<Day07>(),
+    }
 }
@@ -0,0 +1,241 @@
+use common::{Error, Result};
+use petgraph::algo::{all_simple_paths, toposort};
+use petgraph::graphmap::GraphMap;
+use petgraph::{Directed, Incoming};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+// Looks `bag` up, returning the name as borrowed by the graph.
+pub fn node<'a>(graph: &GraphMap<&'a str, u32, Directed>, bag: &str) -> Result<&'a str> {
+    graph
+        .nodes()
+        .find(|&node| node == bag)
+        .ok_or_else(|| Error::invalid_argument(format!("Unknown bag '{}'", bag)))
+}
+
+// A chain of bags that ends up inside its first bag, if there is any.
+pub fn find_cycle<'a>(graph: &GraphMap<&'a str, u32, Directed>) -> Option<Vec<&'a str>> {
+    let start = toposort(graph, None).err()?.node_id();
+    let mut parents: HashMap<&str, &str> = HashMap::new();
+    let mut queue = VecDeque::from(vec![start]);
+
+    while let Some(bag) = queue.pop_front() {
+        for inner in graph.neighbors(bag) {
+            if inner == start {
+                let mut cycle = vec![start];
+                let mut at = bag;
+
+                while at != start {
+                    cycle.push(at);
+                    at = parents[at];
+                }
+
+                cycle.push(start);
+                cycle.reverse();
+                return Some(cycle);
+            }
+
+            if !parents.contains_key(inner) {
+                parents.insert(inner, bag);
+                queue.push_back(inner);
+            }
+        }
+    }
+
+    None
+}
+
+fn cycle_error(bag: &str) -> Error {
+    Error::invalid_argument(format!("Bag '{}' ends up inside itself", bag))
+}
+
+// Every bag that can eventually hold `bag`, sorted by name. Walks the rules
+// backwards once instead of searching forwards from every bag.
+pub fn containers<'a>(graph: &GraphMap<&'a str, u32, Directed>, bag: &str) -> Result<Vec<&'a str>> {
+    let bag = node(graph, bag)?;
+    let mut seen = HashSet::new();
+    let mut queue = vec![bag];
+
+    while let Some(inner) = queue.pop() {
+        for outer in graph.neighbors_directed(inner, Incoming) {
+            if seen.insert(outer) {
+                queue.push(outer);
+            }
+        }
+    }
+
+    seen.remove(bag);
+
+    let mut containers: Vec<&str> = seen.into_iter().collect();
+    containers.sort_unstable();
+
+    Ok(containers)
+}
+
+// `None` marks a bag whose count is still being worked out, so finding it
+// again means the rules loop.
+fn count<'a>(
+    graph: &GraphMap<&'a str, u32, Directed>,
+    bag: &'a str,
+    counts: &mut HashMap<&'a str, Option<u32>>,
+) -> Result<u32> {
+    match counts.get(bag) {
+        Some(Some(count)) => return Ok(*count),
+        Some(None) => return Err(cycle_error(bag)),
+        None => counts.insert(bag, None),
+    };
+
+    let mut total: u32 = 0;
+
+    for (_, inner, &amount) in graph.edges(bag) {
+        total = count(graph, inner, counts)?
+            .checked_add(1)
+            .and_then(|bags| bags.checked_mul(amount))
+            .and_then(|bags| bags.checked_add(total))
+            .ok_or_else(|| {
+                Error::no_solution(format!("Bag '{}' holds more than {} bags", bag, u32::MAX))
+            })?;
+    }
+
+    counts.insert(bag, Some(total));
+    Ok(total)
+}
+
+// How many bags `bag` holds, counting every level of nesting. Each bag is
+// only counted once, however many bags it appears in.
+pub fn contents(graph: &GraphMap<&str, u32, Directed>, bag: &str) -> Result<u32> {
+    count(graph, node(graph, bag)?, &mut HashMap::new())
+}
+
+// Every way `outer` can hold `inner`, from the outermost bag inwards.
+pub fn paths<'a>(
+    graph: &GraphMap<&'a str, u32, Directed>,
+    outer: &str,
+    inner: &str,
+) -> Result<Vec<Vec<&'a str>>> {
+    let (outer, inner) = (node(graph, outer)?, node(graph, inner)?);
+    let mut paths: Vec<Vec<&str>> = all_simple_paths(graph, outer, inner, 0, None).collect();
+    paths.sort();
+
+    Ok(paths)
+}
+
+fn nesting<'a>(
+    graph: &GraphMap<&'a str, u32, Directed>,
+    bag: &'a str,
+    chains: &mut HashMap<&'a str, Vec<&'a str>>,
+) -> Vec<&'a str> {
+    if let Some(chain) = chains.get(bag) {
+        return chain.clone();
+    }
+
+    let mut chain = graph
+        .neighbors(bag)
+        .map(|inner| nesting(graph, inner, chains))
+        .max_by(|a, b| a.len().cmp(&b.len()).then_with(|| b.cmp(a)))
+        .unwrap_or_default();
+    chain.insert(0, bag);
+    chains.insert(bag, chain.clone());
+
+    chain
+}
+
+// The longest chain of bags inside bags, starting from `bag` or from any bag.
+// Ties go to the chain that sorts first.
+pub fn deepest<'a>(
+    graph: &GraphMap<&'a str, u32, Directed>,
+    bag: Option<&str>,
+) -> Result<Vec<&'a str>> {
+    if let Some(cycle) = find_cycle(graph) {
+        return Err(cycle_error(cycle[0]));
+    }
+
+    let mut chains = HashMap::new();
+    let starts: Vec<&str> = match bag {
+        Some(bag) => vec![node(graph, bag)?],
+        None => graph.nodes().collect(),
+    };
+
+    Ok(starts
+        .into_iter()
+        .map(|start| nesting(graph, start, &mut chains))
+        .max_by(|a, b| a.len().cmp(&b.len()).then_with(|| b.cmp(a)))
+        .unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    const EXAMPLE: &str = include_str!("../input/test.txt");
+
+    #[test]
+    fn answers_queries_for_any_bag() {
+        let graph = parse(EXAMPLE.lines()).unwrap();
+
+        assert_eq!(
+            containers(&graph, "shiny gold").unwrap(),
+            vec!["bright white", "dark orange", "light red", "muted yellow"]
+        );
+        assert_eq!(containers(&graph, "light red").unwrap(), Vec::<&str>::new());
+        assert_eq!(containers(&graph, "faded blue").unwrap().len(), 7);
+        assert_eq!(contents(&graph, "shiny gold"), Ok(32));
+        assert_eq!(contents(&graph, "dark olive"), Ok(7));
+        assert!(contents(&graph, "mirrored teal").is_err());
+    }
+
+    #[test]
+    fn lists_paths_between_bags() {
+        let graph = parse(EXAMPLE.lines()).unwrap();
+
+        assert_eq!(
+            paths(&graph, "light red", "shiny gold").unwrap(),
+            vec![
+                vec!["light red", "bright white", "shiny gold"],
+                vec!["light red", "muted yellow", "shiny gold"],
+            ]
+        );
+        assert_eq!(paths(&graph, "faded blue", "light red").unwrap().len(), 0);
+        assert!(paths(&graph, "light red", "mirrored teal").is_err());
+    }
+
+    #[test]
+    fn stops_on_cycles_and_overflows() {
+        let cycle = "light red bags contain 1 bright white bag.
+bright white bags contain 2 light red bags.";
+        let graph = parse(cycle.lines()).unwrap();
+
+        assert_eq!(find_cycle(&graph).unwrap().len(), 3);
+        assert!(contents(&graph, "light red").is_err());
+        assert!(deepest(&graph, None).is_err());
+        assert_eq!(containers(&graph, "light red"), Ok(vec!["bright white"]));
+        assert_eq!(find_cycle(&parse(EXAMPLE.lines()).unwrap()), None);
+
+        let wide = "light red bags contain 65536 bright white bags.
+bright white bags contain 65536 faded blue bags.
+faded blue bags contain no other bags.";
+        let error = contents(&parse(wide.lines()).unwrap(), "light red").unwrap_err();
+
+        assert_eq!(error.kind, common::ErrorKind::NoSolution);
+    }
+
+    #[test]
+    fn finds_the_deepest_nesting() {
+        let graph = parse(EXAMPLE.lines()).unwrap();
+
+        assert_eq!(
+            deepest(&graph, None).unwrap(),
+            vec![
+                "dark orange",
+                "bright white",
+                "shiny gold",
+                "dark olive",
+                "dotted black"
+            ]
+        );
+        assert_eq!(
+            deepest(&graph, Some("vibrant plum")).unwrap(),
+            vec!["vibrant plum", "dotted black"]
+        );
+    }
+}
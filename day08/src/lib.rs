@@ -0,0 +1,185 @@
+use common::{parse_value, Context, Error, Result, Solution};
+use regex::Regex;
+use std::collections::HashSet;
+
+#[macro_use]
+extern crate lazy_static;
+
+lazy_static! {
+    static ref INSTRUCTION: Regex = Regex::new(r"^(nop|acc|jmp) (-\d+|\+\d+)$").unwrap();
+}
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Acc(isize),
+    Nop(isize),
+    Jmp(isize),
+}
+#[derive(Debug, Clone)]
+pub enum ExecutionStatus {
+    Success,
+    Failure,
+}
+
+#[derive(Debug, Clone)]
+pub struct Computer {
+    instructions: Vec<Instruction>,
+    accumulator: isize,
+    ip: isize,
+    execution_status: Option<ExecutionStatus>,
+}
+
+impl Computer {
+    pub fn parse<'a, I>(lines: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut instructions = Vec::new();
+
+        for (row, instruction) in lines.into_iter().enumerate() {
+            let instruction = match INSTRUCTION
+                .captures(instruction)
+                .and_then(|captures| Some((captures.get(1)?.as_str(), captures.get(2)?.as_str())))
+            {
+                Some((op, value)) => {
+                    let value = parse_value::<isize>(value)
+                        .at_line(row + 1)
+                        .at_column(op.len() + 2)?;
+
+                    match op {
+                        "acc" => Instruction::Acc(value),
+                        "nop" => Instruction::Nop(value),
+                        _ => Instruction::Jmp(value),
+                    }
+                }
+                None => {
+                    return Err(Error::parse(format!(
+                        "Could not parse operation '{}'",
+                        instruction
+                    ))
+                    .at_line(row + 1))
+                }
+            };
+
+            instructions.push(instruction);
+        }
+
+        Ok(Computer {
+            instructions,
+            accumulator: 0,
+            ip: 0,
+            execution_status: None,
+        })
+    }
+
+    pub fn execute(&mut self) {
+        let instructions_len = self.instructions.len();
+        let mut history: HashSet<isize> = HashSet::new();
+
+        while !history.contains(&self.ip) && (self.ip as usize) < instructions_len {
+            history.insert(self.ip);
+
+            match self.instructions[self.ip as usize] {
+                Instruction::Acc(value) => {
+                    self.accumulator += value;
+                    self.ip += 1;
+                }
+                Instruction::Jmp(value) => self.ip += value,
+                Instruction::Nop(_) => self.ip += 1,
+            }
+        }
+
+        if (self.ip as usize) == instructions_len {
+            self.execution_status = Some(ExecutionStatus::Success);
+        } else {
+            self.execution_status = Some(ExecutionStatus::Failure);
+        }
+    }
+
+    pub fn replace(&mut self, instruction: Instruction, ip: usize) {
+        self.instructions[ip] = instruction;
+    }
+
+    pub fn accumulator(&self) -> isize {
+        self.accumulator
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    pub fn executed_successfully(&self) -> bool {
+        matches!(self.execution_status, Some(ExecutionStatus::Success))
+    }
+}
+
+pub fn part1(computer: &mut Computer) -> isize {
+    computer.execute();
+    computer.accumulator
+}
+
+pub fn part2(computer: &mut Computer) -> Result<isize> {
+    for (line, instruction) in computer.instructions.iter().enumerate() {
+        let instruction = match instruction {
+            Instruction::Nop(value) => Instruction::Jmp(*value),
+            Instruction::Jmp(value) => Instruction::Nop(*value),
+            Instruction::Acc(_) => continue,
+        };
+
+        let mut new_computer = computer.clone();
+        new_computer.replace(instruction, line);
+        new_computer.execute();
+
+        if new_computer.executed_successfully() {
+            return Ok(new_computer.accumulator);
+        }
+    }
+
+    Err(Error::no_solution(
+        "No single patch makes the program terminate",
+    ))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = Computer;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Computer::parse(input.lines())
+    }
+
+    fn part1(computer: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(part1(&mut computer.clone()))
+    }
+
+    fn part2(computer: &Self::Input<'_>) -> Result<Self::Part2> {
+        part2(&mut computer.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.txt");
+
+    #[test]
+    fn example() {
+        let computer = Computer::parse(EXAMPLE.lines()).unwrap();
+
+        assert_eq!(part1(&mut computer.clone()), 5);
+        assert_eq!(part2(&mut computer.clone()), Ok(8));
+    }
+
+    #[test]
+    fn reports_invalid_instructions() {
+        let error = Computer::parse("nop +0\njmp 4\n".lines()).unwrap_err();
+
+        assert_eq!(error.line, Some(2));
+    }
+}
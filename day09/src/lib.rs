@@ -0,0 +1,134 @@
+use common::{parse_value, Context, Error, Result, Solution};
+
+pub fn pair_exists(numbers: &[u64], number: u64) -> bool {
+    let mut start = 0;
+    let mut end = numbers.len() - 1;
+
+    while start != end {
+        let sum = numbers[start] + numbers[end];
+
+        if sum > number {
+            end -= 1;
+        } else if sum < number {
+            start += 1;
+        } else {
+            return true;
+        }
+    }
+
+    false
+}
+
+pub fn find_subset(numbers: &[u64], search: u64, position: usize) -> Result<(usize, usize)> {
+    if numbers.is_empty() {
+        return Err(Error::no_solution("Subset not found"));
+    }
+
+    let mut current_sum: u64 = numbers[0];
+    let mut start: usize = 0;
+
+    for i in 1..position {
+        while current_sum > search && start < i - 1 {
+            current_sum -= numbers[start];
+            start += 1;
+        }
+
+        if current_sum == search {
+            return Ok((start, i));
+        }
+
+        current_sum += numbers[i];
+    }
+
+    Err(Error::no_solution("Subset not found"))
+}
+
+pub fn find_invalid(numbers: &[u64], preamble: usize) -> Result<(u64, usize)> {
+    if preamble < 2 {
+        return Err(Error::invalid_argument(
+            "Preamble must have at least two numbers",
+        ));
+    }
+
+    let next_numbers = numbers.iter().skip(preamble);
+
+    for (position, (subset, &next_number)) in
+        numbers.windows(preamble).zip(next_numbers).enumerate()
+    {
+        let mut subset: Vec<u64> = subset.to_vec();
+        subset.sort();
+
+        if !pair_exists(&subset, next_number) {
+            return Ok((next_number, (preamble + position)));
+        }
+    }
+
+    Err(Error::no_solution("Nothing wrong with the input"))
+}
+
+pub fn part1(numbers: &[u64]) -> Result<u64> {
+    let (invalid, _) = find_invalid(numbers, 25)?;
+    Ok(invalid)
+}
+
+pub fn part2(numbers: &[u64]) -> Result<u64> {
+    let (invalid, position) = find_invalid(numbers, 25)?;
+    let (start, end) = find_subset(numbers, invalid, position)?;
+
+    let mut subset: Vec<u64> = numbers[start..end].to_vec();
+    subset.sort();
+    Ok(subset[0] + subset[subset.len() - 1])
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<u64>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, x)| parse_value::<u64>(x).at_line(index + 1))
+            .collect()
+    }
+
+    fn part1(numbers: &Self::Input<'_>) -> Result<Self::Part1> {
+        part1(numbers)
+    }
+
+    fn part2(numbers: &Self::Input<'_>) -> Result<Self::Part2> {
+        part2(numbers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.txt");
+
+    fn numbers() -> Vec<u64> {
+        Day09::parse(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn example() {
+        let numbers = numbers();
+        let (invalid, position) = find_invalid(&numbers, 5).unwrap();
+        let (start, end) = find_subset(&numbers, invalid, position).unwrap();
+
+        assert_eq!(invalid, 127);
+        assert_eq!(&numbers[start..end], &[15, 25, 47, 40]);
+    }
+
+    #[test]
+    fn reports_valid_input() {
+        assert!(find_invalid(&numbers(), 25).is_err());
+        assert!(find_invalid(&numbers(), 1).is_err());
+    }
+}
@@ -0,0 +1,85 @@
+use common::{parse_value, Context, Result, Solution};
+
+pub fn part1(numbers: &[usize]) -> usize {
+    let (mut ones, mut threes) = (0, 0);
+
+    for window in numbers.windows(2) {
+        match window[1] - window[0] {
+            1 => ones += 1,
+            3 => threes += 1,
+            _ => (),
+        }
+    }
+
+    ones * threes
+}
+
+pub fn part2(mut numbers: Vec<usize>) -> usize {
+    let last_index = numbers.len() - 1;
+
+    for i in 0..numbers.len() {
+        numbers[i] = numbers[i + 1..]
+            .iter()
+            .take_while(|n| (**n - numbers[i]) <= 3)
+            .count();
+    }
+
+    numbers[last_index] = 1;
+
+    (0..last_index)
+        .rev()
+        .for_each(|i| numbers[i] = numbers[i + 1..i + 1 + numbers[i]].iter().sum());
+
+    numbers[0]
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input<'a> = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let mut numbers: Vec<usize> = input
+            .lines()
+            .enumerate()
+            .map(|(index, x)| parse_value::<usize>(x).at_line(index + 1))
+            .collect::<Result<_>>()?;
+        numbers.push(0);
+        numbers.sort();
+        numbers.push(numbers[numbers.len() - 1] + 3);
+        Ok(numbers)
+    }
+
+    fn part1(numbers: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(part1(numbers))
+    }
+
+    fn part2(numbers: &Self::Input<'_>) -> Result<Self::Part2> {
+        Ok(part2(numbers.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let adapters = Day10::parse(include_str!("../input/test.txt")).unwrap();
+
+        assert_eq!(part1(&adapters), 35);
+        assert_eq!(part2(adapters), 8);
+    }
+
+    #[test]
+    fn larger_example() {
+        let adapters = Day10::parse(include_str!("../input/test2.txt")).unwrap();
+
+        assert_eq!(part1(&adapters), 220);
+        assert_eq!(part2(adapters), 19208);
+    }
+}
@@ -1,3 +1,6 @@
+use common::input::grid;
+use common::{Error, Result};
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Grid2D {
     points: Vec<Vec<char>>,
@@ -6,16 +9,23 @@ pub struct Grid2D {
 }
 
 impl Grid2D {
-    pub fn new(input: &str) -> Grid2D {
-        let points: Vec<Vec<_>> = input.lines().map(|l| l.chars().collect()).collect();
-        let rows = points.len() as isize;
-        let columns = points[0].len() as isize;
+    pub fn new(input: &str) -> Result<Grid2D> {
+        let points = grid(input)?;
+        let columns = points[0].len();
+
+        for (row, line) in points.iter().enumerate() {
+            if let Some(column) = line.iter().position(|c| !matches!(c, 'L' | '#' | '.')) {
+                return Err(Error::parse(format!("Invalid square '{}'", line[column]))
+                    .at_line(row + 1)
+                    .at_column(column + 1));
+            }
+        }
 
-        Grid2D {
+        Ok(Grid2D {
+            rows: points.len() as isize,
+            columns: columns as isize,
             points,
-            rows,
-            columns,
-        }
+        })
     }
 
     pub fn get(&self, row: isize, column: isize) -> Option<char> {
@@ -35,40 +45,12 @@ impl Grid2D {
         }
     }
 
-    pub fn neighbors(&self, row: isize, column: isize) -> Vec<char> {
-        vec![
-            self.get(row, column - 1),
-            self.get(row + 1, column - 1),
-            self.get(row + 1, column),
-            self.get(row + 1, column + 1),
-            self.get(row, column + 1),
-            self.get(row - 1, column + 1),
-            self.get(row - 1, column),
-            self.get(row - 1, column - 1),
-        ]
-        .into_iter()
-        .filter_map(|e| e)
-        .collect()
-    }
-
-    pub fn in_sight(
-        &self,
-        row: isize,
-        column: isize,
-        row_step: isize,
-        column_step: isize,
-    ) -> LineOfSightIterator {
-        LineOfSightIterator::new(&self, row, column, row_step, column_step)
-    }
-
-    #[allow(dead_code)]
-    pub fn iter(&self) -> GridIterator {
-        GridIterator::new(&self)
+    pub fn iter(&self) -> GridIterator<'_> {
+        GridIterator::new(self)
     }
 
-    #[allow(dead_code)]
-    pub fn points_iter(&self) -> PointIterator {
-        PointIterator::new(&self)
+    pub fn points_iter(&self) -> PointIterator<'_> {
+        PointIterator::new(self)
     }
 }
 
@@ -79,7 +61,6 @@ pub struct GridIterator<'a> {
 }
 
 impl<'a> GridIterator<'a> {
-    #[allow(dead_code)]
     pub fn new(grid: &'a Grid2D) -> Self {
         Self {
             grid,
@@ -113,7 +94,6 @@ pub struct PointIterator<'a> {
 }
 
 impl<'a> PointIterator<'a> {
-    #[allow(dead_code)]
     pub fn new(grid: &'a Grid2D) -> Self {
         Self {
             grid,
@@ -130,9 +110,7 @@ impl<'a> Iterator for PointIterator<'a> {
         let coord = self.grid.get(self.row, self.column);
         let (row, column) = (self.row, self.column);
 
-        if coord.is_none() {
-            return None;
-        }
+        coord?;
 
         if self.column + 1 < self.grid.columns {
             self.column += 1;
@@ -144,42 +122,3 @@ impl<'a> Iterator for PointIterator<'a> {
         Some((row, column))
     }
 }
-
-pub struct LineOfSightIterator<'a> {
-    grid: &'a Grid2D,
-    row: isize,
-    column: isize,
-    row_step: isize,
-    column_step: isize,
-}
-
-impl<'a> LineOfSightIterator<'a> {
-    fn new(
-        grid: &'a Grid2D,
-        row: isize,
-        column: isize,
-        row_step: isize,
-        column_step: isize,
-    ) -> LineOfSightIterator<'a> {
-        LineOfSightIterator {
-            grid,
-            row,
-            column,
-            row_step,
-            column_step,
-        }
-    }
-}
-
-impl<'a> Iterator for LineOfSightIterator<'a> {
-    type Item = char;
-
-    fn next(&mut self) -> Option<char> {
-        let point = self.grid.get(self.row, self.column);
-
-        self.row += self.row_step;
-        self.column += self.column_step;
-
-        point
-    }
-}
@@ -0,0 +1,112 @@
+use automaton::topology::{square, Square};
+use automaton::{Automaton, Dense, Rule};
+use common::{Error, Result, Solution};
+pub use grid::Grid2D;
+
+pub mod grid;
+
+static DIRECTIONS: &[(isize, isize)] = &[
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+// Only seats take part in the simulation; floor never changes.
+pub fn seating<F>(grid: &Grid2D, neighbours: F, tolerance: usize) -> Dense<Square>
+where
+    F: Fn(&Square) -> Vec<Square>,
+{
+    let seats = grid
+        .points_iter()
+        .map(|(row, column)| ((row, column), grid.get(row, column).unwrap()))
+        .filter(|&(_, seat)| seat != '.')
+        .map(|(point, seat)| (point, seat == '#'));
+
+    Dense::new(
+        seats,
+        neighbours,
+        Rule::new(&[0], &(0..tolerance).collect::<Vec<_>>()),
+    )
+}
+
+pub fn visible_seats(grid: &Grid2D, (row, column): Square) -> Vec<Square> {
+    DIRECTIONS
+        .iter()
+        .filter_map(|&(row_step, column_step)| {
+            (1..)
+                .map(|distance| (row + row_step * distance, column + column_step * distance))
+                .take_while(|&(row, column)| grid.get(row, column).is_some())
+                .find(|&(row, column)| grid.get(row, column) != Some('.'))
+        })
+        .collect()
+}
+
+// Far more than any layout needs; the puzzle inputs settle within a hundred.
+const MAX_GENERATIONS: usize = 10_000;
+
+fn settle(mut seats: impl Automaton) -> Result<usize> {
+    match seats.step_until_stable(MAX_GENERATIONS) {
+        Some(_) => Ok(seats.population()),
+        None => Err(Error::no_solution(format!(
+            "Seating is still changing after {} rounds",
+            MAX_GENERATIONS
+        ))),
+    }
+}
+
+pub fn part1(grid: &Grid2D) -> Result<usize> {
+    settle(seating(grid, square, 4))
+}
+
+pub fn part2(grid: &Grid2D) -> Result<usize> {
+    settle(seating(grid, |&seat| visible_seats(grid, seat), 5))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input<'a> = Grid2D;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        Grid2D::new(input)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> Result<Self::Part1> {
+        part1(grid)
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> Result<Self::Part2> {
+        part2(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.txt");
+
+    #[test]
+    fn example() {
+        let grid = Grid2D::new(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&grid), Ok(37));
+        assert_eq!(part2(&grid), Ok(26));
+    }
+
+    #[test]
+    fn reports_invalid_layouts() {
+        assert_eq!(Grid2D::new("L.L\nL.\n").unwrap_err().line, Some(2));
+        assert_eq!(Grid2D::new("L.L\nL?L\n").unwrap_err().column, Some(2));
+        assert!(Grid2D::new("").is_err());
+    }
+}
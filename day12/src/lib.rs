@@ -0,0 +1,232 @@
+use common::{parse_value, Context, Error, Result, Solution};
+
+fn invalid_movement(direction: &char) -> Error {
+    Error::invalid_argument(format!("Invalid movement '{}'", direction))
+}
+
+// Counter-clockwise rotation in degrees, normalised to 0, 90, 180 or 270.
+fn rotation(turn: &char, degrees: i32) -> Result<i32> {
+    if degrees % 90 != 0 {
+        return Err(Error::invalid_argument(format!(
+            "Invalid rotation '{}'",
+            degrees
+        )));
+    }
+
+    match &turn {
+        'R' => Ok((-degrees).rem_euclid(360)),
+        'L' => Ok(degrees.rem_euclid(360)),
+        _ => Err(Error::invalid_argument(format!("Invalid turn '{}'", turn))),
+    }
+}
+
+pub struct Ship {
+    angle: i32,
+    x: i32,
+    y: i32,
+    waypoint_x: i32,
+    waypoint_y: i32,
+}
+
+impl Default for Ship {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Ship {
+    pub fn new() -> Self {
+        Self {
+            angle: 0,
+            x: 0,
+            y: 0,
+            waypoint_x: 10,
+            waypoint_y: 1,
+        }
+    }
+
+    pub fn apply_without_waypoint(&mut self, command: &char, value: i32) -> Result<()> {
+        match &command {
+            'R' | 'L' => self.turn(command, value),
+            'F' => match self.angle {
+                0 => self.advance(&'E', value),
+                90 => self.advance(&'N', value),
+                180 => self.advance(&'W', value),
+                270 => self.advance(&'S', value),
+                angle => Err(Error::invalid_argument(format!("Invalid angle {}", angle))),
+            },
+            _ => self.advance(command, value),
+        }
+    }
+
+    pub fn advance(&mut self, direction: &char, distance: i32) -> Result<()> {
+        match &direction {
+            'E' => self.x += distance,
+            'W' => self.x -= distance,
+            'N' => self.y += distance,
+            'S' => self.y -= distance,
+            _ => return Err(invalid_movement(direction)),
+        }
+
+        Ok(())
+    }
+
+    pub fn turn(&mut self, turn: &char, degrees: i32) -> Result<()> {
+        self.angle = (self.angle + rotation(turn, degrees)?) % 360;
+        Ok(())
+    }
+
+    pub fn apply_with_waypoint(&mut self, command: &char, value: i32) -> Result<()> {
+        match &command {
+            'R' | 'L' => self.rotate_waypoint(command, value),
+            'F' => {
+                self.x += self.waypoint_x * value;
+                self.y += self.waypoint_y * value;
+                Ok(())
+            }
+            _ => self.advance_waypoint(command, value),
+        }
+    }
+
+    pub fn advance_waypoint(&mut self, direction: &char, distance: i32) -> Result<()> {
+        match &direction {
+            'E' => self.waypoint_x += distance,
+            'W' => self.waypoint_x -= distance,
+            'N' => self.waypoint_y += distance,
+            'S' => self.waypoint_y -= distance,
+            _ => return Err(invalid_movement(direction)),
+        }
+
+        Ok(())
+    }
+
+    pub fn rotate_waypoint(&mut self, turn: &char, degrees: i32) -> Result<()> {
+        let (x, y) = (self.waypoint_x, self.waypoint_y);
+
+        match rotation(turn, degrees)? {
+            90 => {
+                self.waypoint_x = -y;
+                self.waypoint_y = x;
+            }
+            180 => {
+                self.waypoint_x = -x;
+                self.waypoint_y = -y;
+            }
+            270 => {
+                self.waypoint_x = y;
+                self.waypoint_y = -x;
+            }
+            _ => (),
+        }
+
+        Ok(())
+    }
+
+    pub fn manhattan_distance(&self) -> i32 {
+        self.x.abs() + self.y.abs()
+    }
+}
+
+pub fn parse_command(line: &str) -> Result<(char, i32)> {
+    let mut chars = line.chars();
+
+    let command = match chars.next() {
+        Some(c) if "NSEWLRF".contains(c) => c,
+        Some(c) => return Err(Error::parse(format!("Invalid command '{}'", c)).at_column(1)),
+        None => return Err(Error::parse("Empty command")),
+    };
+    let value = parse_value::<i32>(chars.as_str()).at_column(2)?;
+
+    if (command == 'L' || command == 'R') && value % 90 != 0 {
+        return Err(Error::parse(format!("Invalid rotation '{}'", value)).at_column(2));
+    }
+
+    Ok((command, value))
+}
+
+pub fn parse(input: &str) -> Result<Vec<(char, i32)>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_command(line).at_line(index + 1))
+        .collect()
+}
+
+pub fn part1(commands: &[(char, i32)]) -> Result<i32> {
+    let mut ship = Ship::new();
+
+    for (index, &(command, value)) in commands.iter().enumerate() {
+        ship.apply_without_waypoint(&command, value)
+            .at_line(index + 1)?;
+    }
+
+    Ok(ship.manhattan_distance())
+}
+
+pub fn part2(commands: &[(char, i32)]) -> Result<i32> {
+    let mut ship = Ship::new();
+
+    for (index, &(command, value)) in commands.iter().enumerate() {
+        ship.apply_with_waypoint(&command, value)
+            .at_line(index + 1)?;
+    }
+
+    Ok(ship.manhattan_distance())
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input<'a> = Vec<(char, i32)>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(commands: &Self::Input<'_>) -> Result<Self::Part1> {
+        part1(commands)
+    }
+
+    fn part2(commands: &Self::Input<'_>) -> Result<Self::Part2> {
+        part2(commands)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.txt");
+
+    #[test]
+    fn example() {
+        let commands = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&commands), Ok(25));
+        assert_eq!(part2(&commands), Ok(286));
+    }
+
+    #[test]
+    fn reports_invalid_commands() {
+        assert_eq!(parse("F10\nX3\n").unwrap_err().line, Some(2));
+        assert!(parse_command("R45").is_err());
+        assert!(parse_command("F").is_err());
+        assert!(parse_command("L3600000000").is_err());
+    }
+
+    #[test]
+    fn normalises_large_turns() {
+        assert_eq!(parse("R450\nF10\n").and_then(|c| part1(&c)), Ok(10));
+        assert_eq!(
+            parse("L450\nF10\nR810\nF3\n").and_then(|c| part1(&c)),
+            Ok(13)
+        );
+        assert_eq!(parse("R450\nF1\n").and_then(|c| part2(&c)), Ok(11));
+        assert!(Ship::new().turn(&'L', 45).is_err());
+        assert!(Ship::new().advance(&'F', 1).is_err());
+    }
+}
@@ -0,0 +1,117 @@
+use common::{column_of, parse_value, Context, Error, Result, Solution};
+use math::chinese_remainder;
+
+pub fn parse(input: &str) -> Result<(u64, Vec<Option<u64>>)> {
+    let mut lines = input.lines();
+
+    let timestamp = match lines.next() {
+        Some(line) => parse_value::<u64>(line).at_line(1)?,
+        None => return Err(Error::parse("Missing timestamp")),
+    };
+
+    let schedule = match lines.next() {
+        Some(line) => line,
+        None => return Err(Error::parse("Missing bus schedule").at_line(2)),
+    };
+
+    let buses = schedule
+        .split(',')
+        .map(|part| {
+            match part {
+                "x" => Ok(None),
+                _ => match parse_value::<u64>(part) {
+                    Ok(id) if id > 0 => Ok(Some(id)),
+                    _ => Err(Error::parse(format!("Invalid bus '{}'", part))),
+                },
+            }
+            .at_line(2)
+            .at_column(column_of(schedule, part))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok((timestamp, buses))
+}
+
+pub fn part1(timestamp: u64, buses: &[Option<u64>]) -> Result<u64> {
+    let mut bus_departures: Vec<(u64, u64)> = buses
+        .iter()
+        .flatten()
+        .map(|&id| (id, (timestamp / id) * id + id))
+        .collect();
+
+    bus_departures.sort_by_key(|&(_, dep_a)| dep_a);
+
+    match bus_departures.first() {
+        Some(&(id, departure)) => Ok((departure - timestamp) * id),
+        None => Err(Error::no_solution("No buses in service")),
+    }
+}
+
+pub fn part2(schedule: &[Option<u64>]) -> Result<i64> {
+    let buses: Vec<(i64, i64)> = schedule
+        .iter()
+        .enumerate()
+        .filter_map(|(offset, bus_id)| bus_id.map(|id| (-(offset as i64), id as i64)))
+        .collect();
+
+    match chinese_remainder(&buses) {
+        Some((timestamp, _)) => Ok(timestamp),
+        None => Err(Error::no_solution("No timestamp satisfies every bus")),
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input<'a> = (u64, Vec<Option<u64>>);
+    type Part1 = u64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1((timestamp, buses): &Self::Input<'_>) -> Result<Self::Part1> {
+        part1(*timestamp, buses)
+    }
+
+    fn part2((_, buses): &Self::Input<'_>) -> Result<Self::Part2> {
+        part2(buses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (timestamp, buses) = parse(include_str!("../input/test.txt")).unwrap();
+
+        assert_eq!(part1(timestamp, &buses), Ok(295));
+        assert_eq!(part2(&buses), Ok(1068781));
+    }
+
+    #[test]
+    fn example_schedules() {
+        let earliest = |schedule: &str| {
+            parse(&format!("0\n{}\n", schedule)).and_then(|(_, buses)| part2(&buses))
+        };
+
+        assert_eq!(earliest("17,x,13,19"), Ok(3417));
+        assert_eq!(earliest("67,7,59,61"), Ok(754018));
+        assert_eq!(earliest("67,x,7,59,61"), Ok(779210));
+        assert_eq!(earliest("67,7,x,59,61"), Ok(1261476));
+        assert_eq!(earliest("1789,37,47,1889"), Ok(1202161486));
+    }
+
+    #[test]
+    fn reports_invalid_buses() {
+        let error = parse("939\n7,0,x\n").unwrap_err();
+
+        assert_eq!(error.line, Some(2));
+        assert_eq!(error.column, Some(3));
+    }
+}
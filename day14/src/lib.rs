@@ -0,0 +1,161 @@
+use common::{parse_value, Context, Error, Result, Solution};
+use regex::Regex;
+use std::collections::HashMap;
+
+#[macro_use]
+extern crate lazy_static;
+
+lazy_static! {
+    static ref MASK_RE: Regex = Regex::new(r"^mask = ([01X]{36})$").unwrap();
+    static ref MEM_RE: Regex = Regex::new(r"^mem\[(\d+)\] = (\d+)$").unwrap();
+}
+
+#[derive(Debug)]
+pub enum Instruction<'a> {
+    Mask(&'a str),
+    Write(u64, u64),
+}
+
+pub fn parse_instruction(line: &str) -> Result<Instruction<'_>> {
+    if let Some(captures) = MASK_RE.captures(line) {
+        return Ok(Instruction::Mask(captures.get(1).unwrap().as_str()));
+    }
+
+    if let Some(captures) = MEM_RE.captures(line) {
+        let location = captures.get(1).unwrap();
+        let value = captures.get(2).unwrap();
+
+        return Ok(Instruction::Write(
+            parse_value::<u64>(location.as_str()).at_column(location.start() + 1)?,
+            parse_value::<u64>(value.as_str()).at_column(value.start() + 1)?,
+        ));
+    }
+
+    Err(Error::parse(format!("Invalid instruction '{}'", line)))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction<'_>>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_instruction(line).at_line(index + 1))
+        .collect()
+}
+
+pub fn part1(instructions: &[Instruction]) -> u64 {
+    let mut memory: HashMap<u64, u64> = HashMap::new();
+    let mut or_active_mask: u64 = 0;
+    let mut and_active_mask: u64 = 0;
+
+    for instruction in instructions {
+        match *instruction {
+            Instruction::Mask(mask) => {
+                or_active_mask = u64::from_str_radix(&mask.replace("X", "0"), 2).unwrap();
+                and_active_mask = u64::from_str_radix(&mask.replace("X", "1"), 2).unwrap();
+            }
+            Instruction::Write(location, value) => {
+                memory.insert(location, (value | or_active_mask) & and_active_mask);
+            }
+        }
+    }
+
+    memory.values().sum()
+}
+
+fn floating_combinations(mask: u64, location: u64, value: u64, memory: &mut HashMap<u64, u64>) {
+    if mask == 0 {
+        return;
+    }
+
+    let x = mask & (!mask + 1); // get right-most ‘1’ of mask
+    let mask = mask & !x; // clear right-most ‘1’ of mask
+
+    memory.insert(location & !x, value);
+    memory.insert(location | x, value);
+    floating_combinations(mask, location & !x, value, memory);
+    floating_combinations(mask, location | x, value, memory);
+}
+
+pub fn part2(instructions: &[Instruction]) -> u64 {
+    let mut memory: HashMap<u64, u64> = HashMap::new();
+    let mut floating_active_mask: u64 = 0;
+    let mut or_active_mask: u64 = 0;
+
+    for instruction in instructions {
+        match *instruction {
+            Instruction::Mask(mask) => {
+                or_active_mask = u64::from_str_radix(&mask.replace("X", "0"), 2).unwrap();
+
+                let floating_mask = &mask.replace("1", "0");
+                floating_active_mask =
+                    u64::from_str_radix(&floating_mask.replace("X", "1"), 2).unwrap();
+            }
+            Instruction::Write(location, value) => {
+                memory.insert(location | or_active_mask, value);
+
+                floating_combinations(
+                    floating_active_mask,
+                    location | or_active_mask,
+                    value,
+                    &mut memory,
+                );
+            }
+        }
+    }
+
+    memory.values().sum()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input<'a> = Vec<Instruction<'a>>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(instructions: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(part1(instructions))
+    }
+
+    fn part2(instructions: &Self::Input<'_>) -> Result<Self::Part2> {
+        Ok(part2(instructions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let example = parse(include_str!("../input/test.txt")).unwrap();
+        let example_2 = parse(include_str!("../input/test2.txt")).unwrap();
+
+        assert_eq!(part1(&example), 165);
+        assert_eq!(part2(&example_2), 208);
+    }
+
+    #[test]
+    fn floating_bits_cover_every_combination() {
+        let mut memory = HashMap::new();
+        floating_combinations(0b100001, 0b011010, 7, &mut memory);
+
+        let mut locations: Vec<u64> = memory.keys().copied().collect();
+        locations.sort_unstable();
+
+        assert_eq!(locations, vec![0b011010, 0b011011, 0b111010, 0b111011]);
+    }
+
+    #[test]
+    fn reports_invalid_instructions() {
+        let error = parse("mem[8] = 11\nmem[x] = 3\n").unwrap_err();
+
+        assert_eq!(error.line, Some(2));
+    }
+}
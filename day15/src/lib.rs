@@ -0,0 +1,85 @@
+use common::input::comma_list;
+use common::{column_of, parse_value, Context, Error, Result, Solution};
+use std::collections::HashMap;
+
+pub fn parse(input: &str) -> Result<Vec<u32>> {
+    if input.trim().is_empty() {
+        return Err(Error::parse("No starting numbers"));
+    }
+
+    comma_list(input)
+        .map(|v| parse_value::<u32>(v).at_column(column_of(input, v)))
+        .collect()
+}
+
+pub fn pick_nth(numbers: &[u32], nth_number: u32) -> u32 {
+    let mut spoken: HashMap<u32, u32> = HashMap::new();
+    let mut current_turn = 0;
+    let mut last_seen = 0;
+
+    for &number in numbers {
+        last_seen = number;
+        current_turn += 1;
+        spoken.insert(last_seen, current_turn);
+    }
+
+    while current_turn < nth_number {
+        last_seen = match spoken.insert(last_seen, current_turn) {
+            Some(num) => current_turn - num,
+            None => 0,
+        };
+
+        current_turn += 1;
+    }
+
+    last_seen
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input<'a> = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(numbers: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(pick_nth(numbers, 2020))
+    }
+
+    fn part2(numbers: &Self::Input<'_>) -> Result<Self::Part2> {
+        Ok(pick_nth(numbers, 30000000))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let example = parse(include_str!("../input/test.txt")).unwrap();
+
+        assert_eq!(pick_nth(&[0, 3, 6], 4), 0);
+        assert_eq!(pick_nth(&[0, 3, 6], 10), 0);
+        assert_eq!(pick_nth(&example, 2020), 436);
+    }
+
+    #[test]
+    fn other_starting_numbers() {
+        assert_eq!(pick_nth(&[1, 3, 2], 2020), 1);
+        assert_eq!(pick_nth(&[2, 1, 3], 2020), 10);
+        assert_eq!(pick_nth(&[3, 1, 2], 2020), 1836);
+    }
+
+    #[test]
+    fn reports_invalid_numbers() {
+        assert_eq!(parse("0,a,6").unwrap_err().column, Some(3));
+        assert!(parse("\n").is_err());
+    }
+}
@@ -0,0 +1,293 @@
+use common::{column_of, parse_value, Context, Error, Result, Solution};
+use regex::Regex;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+#[macro_use]
+extern crate lazy_static;
+
+lazy_static! {
+    static ref TICKET_RULE_RE: Regex = Regex::new(r"^(.*): (\d+)\-(\d+) or (\d+)-(\d+)$").unwrap();
+}
+
+pub type Ticket = Vec<u32>;
+
+#[derive(Debug, Clone)]
+pub struct Rule {
+    pub first: RangeInclusive<u32>,
+    pub second: RangeInclusive<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Notes {
+    pub rules: Vec<(String, Rule)>,
+    pub your: Ticket,
+    pub nearby: Vec<Ticket>,
+}
+
+impl Notes {
+    pub fn in_any_rule(&self, target: &u32) -> bool {
+        self.rules
+            .iter()
+            .any(|(_, rule)| rule.first.contains(target) || rule.second.contains(target))
+    }
+}
+
+fn parse_ticket(line: &str) -> Result<Ticket> {
+    line.split(',')
+        .map(|entry| parse_value::<u32>(entry).at_column(column_of(line, entry)))
+        .collect()
+}
+
+impl FromStr for Notes {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let mut notes = Notes {
+            rules: Vec::new(),
+            your: Ticket::new(),
+            nearby: Vec::new(),
+        };
+
+        let mut your_ticket_start = false;
+        let mut nearby_tickets_start = false;
+
+        for (index, line) in input.lines().map(|line| line.trim()).enumerate() {
+            match TICKET_RULE_RE.captures(line) {
+                Some(capture) => {
+                    let bound = |group: usize| {
+                        let value = capture.get(group).unwrap();
+                        parse_value::<u32>(value.as_str())
+                            .at_line(index + 1)
+                            .at_column(value.start() + 1)
+                    };
+
+                    notes.rules.push((
+                        capture[1].to_string(),
+                        Rule {
+                            first: bound(2)?..=bound(3)?,
+                            second: bound(4)?..=bound(5)?,
+                        },
+                    ))
+                }
+                None => match line {
+                    "your ticket:" => your_ticket_start = true,
+                    "nearby tickets:" => nearby_tickets_start = true,
+                    _ if line.is_empty() => continue,
+                    _ if !your_ticket_start && !nearby_tickets_start => {
+                        return Err(
+                            Error::parse(format!("Invalid rule '{}'", line)).at_line(index + 1)
+                        )
+                    }
+                    _ => {
+                        let ticket: Ticket = parse_ticket(line).at_line(index + 1)?;
+
+                        if ticket.len() != notes.rules.len() {
+                            return Err(Error::parse(format!(
+                                "Expected {} values, found {}",
+                                notes.rules.len(),
+                                ticket.len()
+                            ))
+                            .at_line(index + 1));
+                        }
+
+                        if nearby_tickets_start {
+                            notes.nearby.push(ticket);
+                        } else {
+                            notes.your = ticket;
+                        }
+                    }
+                },
+            };
+        }
+
+        if notes.your.is_empty() {
+            return Err(Error::parse("Missing your ticket"));
+        }
+
+        Ok(notes)
+    }
+}
+
+pub fn part1(notes: &Notes) -> u32 {
+    notes
+        .nearby
+        .iter()
+        .map(|ticket| {
+            ticket
+                .iter()
+                .filter(|value| !notes.in_any_rule(value))
+                .sum::<u32>()
+        })
+        .sum::<u32>()
+}
+
+pub fn part2(notes: &Notes) -> Result<u64> {
+    let valid_tickets: Vec<Ticket> = notes
+        .nearby
+        .iter()
+        .filter(|ticket| ticket.iter().all(|value| notes.in_any_rule(value)))
+        .cloned()
+        .collect::<Vec<Ticket>>();
+
+    let total_positions = notes.your.len();
+    let mut inferred_positions = vec![false; total_positions];
+    let mut inferred_rules: HashMap<&str, u32> = HashMap::new();
+    let mut positions_inferred_count = 0;
+
+    while positions_inferred_count != total_positions {
+        let previous_count = positions_inferred_count;
+
+        for position in 0..total_positions {
+            if inferred_positions[position] {
+                continue;
+            }
+
+            let mut matching_rules = 0;
+            let mut last_rule_matched = "";
+
+            for (name, rule) in notes.rules.iter() {
+                if inferred_rules.contains_key(name.as_str()) {
+                    continue;
+                }
+
+                let any_invalid = valid_tickets.iter().any(|ticket| {
+                    !rule.first.contains(&ticket[position])
+                        && !rule.second.contains(&ticket[position])
+                });
+
+                if any_invalid {
+                    continue;
+                }
+
+                matching_rules += 1;
+                last_rule_matched = name;
+            }
+
+            if matching_rules == 1 {
+                inferred_rules.insert(last_rule_matched, notes.your[position]);
+                inferred_positions[position] = true;
+                positions_inferred_count += 1;
+            }
+        }
+
+        if positions_inferred_count == previous_count {
+            return Err(Error::no_solution("Ticket fields are ambiguous"));
+        }
+    }
+
+    Ok(inferred_rules
+        .iter()
+        .filter(|(name, _)| name.starts_with("departure"))
+        .fold(1, |acc, (_, &value)| acc * value as u64))
+}
+
+// 1. Find all valid tickets
+// 2. Find the list of valid positions for each rule and store it in a vector
+// 3. Sort from lower to higher the previous vector
+// 4. For each rule (starting with the one with the lowest amount of valid positions)
+//  - Pick a position from the valid list if it hasn't been picked yet
+//  - Check If rule starts with "departure" and multiply accordingly
+//  - Mark position as picked
+pub fn part2_alternative(notes: &Notes) -> Result<u64> {
+    let valid_tickets: Vec<Ticket> = notes
+        .nearby
+        .iter()
+        .filter(|ticket| ticket.iter().all(|value| notes.in_any_rule(value)))
+        .cloned()
+        .collect::<Vec<Ticket>>();
+
+    let mut inferred_rules_matches: Vec<(&str, Vec<u64>)> = Vec::new();
+
+    for (name, rule) in notes.rules.iter() {
+        let mut valid_positions = Vec::new();
+
+        for position in 0..notes.your.len() {
+            let any_invalid = valid_tickets.iter().any(|ticket| {
+                !rule.first.contains(&ticket[position]) && !rule.second.contains(&ticket[position])
+            });
+
+            if any_invalid {
+                continue;
+            }
+
+            valid_positions.push(position as u64);
+        }
+
+        inferred_rules_matches.push((name, valid_positions));
+    }
+
+    inferred_rules_matches.sort_by_key(|(_, count_a)| count_a.len());
+
+    let mut filled_positions: HashSet<u64> = HashSet::new();
+    let mut total: u64 = 1;
+
+    for (name, valid_positions) in inferred_rules_matches.iter().take(notes.your.len()) {
+        let selected_position = valid_positions
+            .iter()
+            .find(|matched_pos| !filled_positions.contains(matched_pos))
+            .ok_or_else(|| Error::no_solution(format!("No position left for '{}'", name)))?;
+
+        if name.starts_with("departure") {
+            total *= notes.your[*selected_position as usize] as u64;
+        }
+
+        filled_positions.insert(*selected_position);
+    }
+
+    Ok(total)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input<'a> = Notes;
+    type Part1 = u32;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        input.parse()
+    }
+
+    fn part1(notes: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(part1(notes))
+    }
+
+    fn part2(notes: &Self::Input<'_>) -> Result<Self::Part2> {
+        part2(notes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let notes: Notes = include_str!("../input/test.txt").parse().unwrap();
+
+        assert_eq!(part1(&notes), 71);
+    }
+
+    #[test]
+    fn infers_departure_fields() {
+        let notes: Notes = include_str!("../input/test2.txt").parse().unwrap();
+
+        assert_eq!(part2(&notes), Ok(156));
+        assert_eq!(part2_alternative(&notes), Ok(156));
+    }
+
+    #[test]
+    fn reports_invalid_tickets() {
+        let error = "class: 1-3 or 5-7\n\nyour ticket:\n7,x\n"
+            .parse::<Notes>()
+            .unwrap_err();
+
+        assert_eq!(error.line, Some(4));
+        assert_eq!(error.column, Some(3));
+    }
+}
@@ -0,0 +1,97 @@
+use automaton::topology::cube;
+use automaton::{Automaton, Rule, Sparse};
+use common::input::lines;
+use common::{Error, Result, Solution};
+
+// The input is a 2-D slice through the origin of an N-dimensional space.
+pub fn parse(input: &str) -> Result<Vec<(i32, i32)>> {
+    let mut active = Vec::new();
+
+    for (y, line) in lines(input).enumerate() {
+        for (x, state) in line.chars().enumerate() {
+            match state {
+                '#' => active.push((x as i32, y as i32)),
+                '.' => (),
+                _ => {
+                    return Err(Error::parse(format!("Invalid cube '{}'", state))
+                        .at_line(y + 1)
+                        .at_column(x + 1))
+                }
+            }
+        }
+    }
+
+    Ok(active)
+}
+
+pub fn simulate<const N: usize>(slice: &[(i32, i32)], cycles: usize) -> usize {
+    let active = slice.iter().map(|&(x, y)| {
+        let mut point = [0; N];
+        point[0] = x;
+        point[1] = y;
+        point
+    });
+    let mut pocket = Sparse::new(active, cube, Rule::new(&[3], &[2, 3]));
+
+    pocket.step_n(cycles);
+    pocket.population()
+}
+
+pub fn run(slice: &[(i32, i32)], dimensions: usize) -> Result<usize> {
+    match dimensions {
+        3 => Ok(simulate::<3>(slice, 6)),
+        4 => Ok(simulate::<4>(slice, 6)),
+        _ => Err(Error::invalid_argument(format!(
+            "Unsupported number of dimensions: {}",
+            dimensions
+        ))),
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input<'a> = Vec<(i32, i32)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(slice: &Self::Input<'_>) -> Result<Self::Part1> {
+        run(slice, 3)
+    }
+
+    fn part2(slice: &Self::Input<'_>) -> Result<Self::Part2> {
+        run(slice, 4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_slice() -> Vec<(i32, i32)> {
+        parse(include_str!("../input/test.txt")).unwrap()
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(run(&example_slice(), 3), Ok(112));
+        assert_eq!(run(&example_slice(), 4), Ok(848));
+    }
+
+    #[test]
+    fn first_cycle() {
+        assert_eq!(simulate::<3>(&example_slice(), 1), 11);
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        assert!(run(&example_slice(), 5).is_err());
+        assert_eq!(parse(".#.\n.x#\n").unwrap_err().column, Some(2));
+    }
+}
@@ -0,0 +1,183 @@
+use common::{Context, Error, Result, Solution};
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Token {
+    Num(u64),
+    LParen,
+    RParen,
+    Plus,
+    Mul,
+}
+
+fn operand(tokens: &[Token], pos: usize) -> Result<(u64, usize)> {
+    match tokens.get(pos) {
+        Some(Token::Num(n)) => Ok((*n, pos + 1)),
+        Some(Token::LParen) => {
+            let (value, pos) = evaluate(tokens, pos + 1)?;
+
+            if pos > tokens.len() {
+                return Err(Error::parse("Unclosed parenthesis"));
+            }
+
+            Ok((value, pos))
+        }
+        Some(token) => Err(Error::parse(format!("Expected operand, found {:?}", token))),
+        None => Err(Error::parse("Unexpected end of expression")),
+    }
+}
+
+pub fn evaluate(tokens: &[Token], pos: usize) -> Result<(u64, usize)> {
+    let (mut exp_value, mut pos) = operand(tokens, pos)?;
+
+    while pos < tokens.len() {
+        match tokens[pos] {
+            Token::Plus => {
+                let (rhs, new_pos) = operand(tokens, pos + 1)?;
+                exp_value += rhs;
+                pos = new_pos;
+            }
+            Token::Mul => {
+                let (rhs, new_pos) = operand(tokens, pos + 1)?;
+                exp_value *= rhs;
+                pos = new_pos;
+            }
+            Token::RParen => break,
+            ref token => {
+                return Err(Error::parse(format!(
+                    "Expected operator, found {:?}",
+                    token
+                )))
+            }
+        }
+    }
+
+    Ok((exp_value, pos + 1))
+}
+
+pub fn tokenize(line: &str) -> Result<Vec<Token>> {
+    let mut tokens: Vec<Token> = Vec::new();
+
+    for (column, char) in line.chars().enumerate() {
+        match char {
+            '(' => tokens.push(Token::LParen),
+            ')' => tokens.push(Token::RParen),
+            '+' => tokens.push(Token::Plus),
+            '*' => tokens.push(Token::Mul),
+            n if n.is_ascii_digit() => tokens.push(Token::Num(n.to_digit(10).unwrap() as u64)),
+            ' ' => continue,
+            _ => {
+                return Err(
+                    Error::parse(format!("Invalid character '{}'", char)).at_column(column + 1)
+                )
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+pub fn value(tokens: &[Token]) -> Result<u64> {
+    let (v, pos) = evaluate(tokens, 0)?;
+
+    if pos <= tokens.len() {
+        return Err(Error::parse("Unbalanced parenthesis"));
+    }
+
+    Ok(v)
+}
+
+// Both precedence rules accept the same expressions, so evaluating once left to
+// right is enough to reject malformed ones.
+pub fn parse_expression(line: &str) -> Result<Vec<Token>> {
+    let tokens = tokenize(line)?;
+    value(&tokens)?;
+
+    Ok(tokens)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<Token>>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_expression(line).at_line(index + 1))
+        .collect()
+}
+
+// Wraps every product's operands in parentheses so addition binds tighter.
+pub fn additions_first(tokens: &[Token]) -> Vec<Token> {
+    let mut rewritten = vec![Token::LParen];
+
+    for token in tokens {
+        match token {
+            Token::LParen => rewritten.extend([Token::LParen, Token::LParen]),
+            Token::RParen => rewritten.extend([Token::RParen, Token::RParen]),
+            Token::Mul => rewritten.extend([Token::RParen, Token::Mul, Token::LParen]),
+            token => rewritten.push(token.clone()),
+        }
+    }
+
+    rewritten.push(Token::RParen);
+    rewritten
+}
+
+pub fn run1(expressions: &[Vec<Token>]) -> Result<u64> {
+    expressions
+        .iter()
+        .try_fold(0, |sum, tokens| Ok(sum + value(tokens)?))
+}
+
+pub fn run2(expressions: &[Vec<Token>]) -> Result<u64> {
+    expressions
+        .iter()
+        .try_fold(0, |sum, tokens| Ok(sum + value(&additions_first(tokens))?))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input<'a> = Vec<Vec<Token>>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(expressions: &Self::Input<'_>) -> Result<Self::Part1> {
+        run1(expressions)
+    }
+
+    fn part2(expressions: &Self::Input<'_>) -> Result<Self::Part2> {
+        run2(expressions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let simple = parse("1 + 2 * 3 + 4 * 5 + 6").unwrap();
+        let nested = parse("1 + (2 * 3) + (4 * (5 + 6))").unwrap();
+        let example = parse(include_str!("../input/test.txt")).unwrap();
+
+        assert_eq!(run1(&simple), Ok(71));
+        assert_eq!(run2(&simple), Ok(231));
+        assert_eq!(run1(&nested), Ok(51));
+        assert_eq!(run2(&nested), Ok(51));
+        assert_eq!(run1(&example), Ok(26335));
+        assert_eq!(run2(&example), Ok(693891));
+    }
+
+    #[test]
+    fn reports_malformed_expressions() {
+        assert!(parse("1 + (2 * 3").is_err());
+        assert!(parse("1 + 2) * 3").is_err());
+        assert!(parse("1 + * 3").is_err());
+        assert!(parse("1 2").is_err());
+        assert_eq!(parse("1 + 2\n3 - 4\n").unwrap_err().line, Some(2));
+    }
+}
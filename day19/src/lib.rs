@@ -0,0 +1,312 @@
+use common::input::{groups, lines};
+use common::{column_of, parse_value, Context, Error, Result, Solution};
+use std::collections::{HashMap, HashSet};
+
+fn atoi(constraint: &str) -> Result<usize> {
+    parse_value(constraint)
+}
+
+#[derive(Debug, Clone)]
+pub enum Rule {
+    Unit(char),
+    SubRules(Vec<usize>),
+    Or(Vec<usize>, Vec<usize>),
+}
+
+fn parse_numbered_rule(line: &str) -> Result<(usize, Rule)> {
+    let (number, rule) = match line.find(": ") {
+        Some(separator) => (&line[..separator], &line[separator + 2..]),
+        None => return Err(Error::parse(format!("Invalid rule '{}'", line))),
+    };
+
+    Ok((
+        atoi(number).at_column(1)?,
+        parse_rule(rule).at_column(column_of(line, rule))?,
+    ))
+}
+
+pub fn parse_rules(rules: &str) -> Result<HashMap<usize, Rule>> {
+    let parsed: HashMap<usize, Rule> = rules
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_numbered_rule(line).at_line(index + 1))
+        .collect::<Result<_>>()?;
+
+    for rule in parsed.values() {
+        let references = match rule {
+            Rule::Unit(_) => vec![],
+            Rule::SubRules(sub_rules) => sub_rules.clone(),
+            Rule::Or(group1, group2) => [group1.as_slice(), group2].concat(),
+        };
+
+        if let Some(missing) = references.iter().find(|r| !parsed.contains_key(r)) {
+            return Err(Error::parse(format!("Undefined rule {}", missing)));
+        }
+    }
+
+    if !parsed.contains_key(&0) {
+        return Err(Error::parse("Undefined rule 0"));
+    }
+
+    check_aliases(&parsed)?;
+
+    Ok(parsed)
+}
+
+fn aliases(rule: &Rule) -> Vec<usize> {
+    match rule {
+        Rule::SubRules(sub_rules) if sub_rules.len() == 1 => vec![sub_rules[0]],
+        Rule::Unit(_) | Rule::SubRules(_) => vec![],
+        Rule::Or(group1, group2) => [group1, group2]
+            .iter()
+            .filter(|group| group.len() == 1)
+            .map(|group| group[0])
+            .collect(),
+    }
+}
+
+fn walk_aliases(
+    rule: usize,
+    rules: &HashMap<usize, Rule>,
+    path: &mut Vec<usize>,
+    done: &mut HashSet<usize>,
+) -> Option<Vec<usize>> {
+    if let Some(start) = path.iter().position(|seen| *seen == rule) {
+        let mut cycle = path[start..].to_vec();
+        cycle.push(rule);
+        return Some(cycle);
+    }
+    if done.contains(&rule) {
+        return None;
+    }
+
+    path.push(rule);
+    for alias in aliases(&rules[&rule]) {
+        if let Some(cycle) = walk_aliases(alias, rules, path, done) {
+            return Some(cycle);
+        }
+    }
+    path.pop();
+    done.insert(rule);
+
+    None
+}
+
+/// Finds rules that expand into each other one for one, such as `0: 1` and
+/// `1: 0`. Matching such rules never consumes input nor grows the stack.
+pub fn find_alias_cycle(rules: &HashMap<usize, Rule>) -> Option<Vec<usize>> {
+    let mut numbers: Vec<usize> = rules.keys().copied().collect();
+    numbers.sort_unstable();
+
+    let mut done = HashSet::new();
+    numbers
+        .into_iter()
+        .find_map(|number| walk_aliases(number, rules, &mut vec![], &mut done))
+}
+
+fn check_aliases(rules: &HashMap<usize, Rule>) -> Result<()> {
+    match find_alias_cycle(rules) {
+        Some(cycle) => Err(Error::parse(format!(
+            "Rules expand into each other without consuming input: {}",
+            cycle
+                .iter()
+                .map(|number| number.to_string())
+                .collect::<Vec<_>>()
+                .join(" -> ")
+        ))),
+        None => Ok(()),
+    }
+}
+
+pub fn parse_rule(rule: &str) -> Result<Rule> {
+    let numbers = |rules: &str| rules.split(' ').map(atoi).collect::<Result<Vec<usize>>>();
+
+    if rule.contains('"') {
+        let unit: Vec<char> = rule.chars().collect();
+
+        match unit.as_slice() {
+            ['"', c, '"'] => Ok(Rule::Unit(*c)),
+            _ => Err(Error::parse(format!("Invalid unit rule '{}'", rule))),
+        }
+    } else if rule.contains('|') {
+        let sub_rules: Vec<Vec<usize>> = rule.split(" | ").map(numbers).collect::<Result<_>>()?;
+
+        match sub_rules.as_slice() {
+            [group1, group2] => Ok(Rule::Or(group1.clone(), group2.clone())),
+            _ => Err(Error::parse(format!(
+                "Expected two alternatives in '{}'",
+                rule
+            ))),
+        }
+    } else {
+        Ok(Rule::SubRules(numbers(rule)?))
+    }
+}
+
+pub fn parse(input: &str) -> Result<(HashMap<usize, Rule>, Vec<&str>)> {
+    match groups(input).collect::<Vec<_>>().as_slice() {
+        [(first_line, rules), (_, messages)] => Ok((
+            parse_rules(rules).offset_line(first_line - 1)?,
+            lines(messages).collect(),
+        )),
+        _ => Err(Error::parse(
+            "Expected rules and messages separated by a blank line",
+        )),
+    }
+}
+
+fn match_unit(
+    unit: char,
+    message: &str,
+    rules: &HashMap<usize, Rule>,
+    stack: &mut Vec<usize>,
+) -> bool {
+    match message.chars().next() {
+        Some(candidate) if candidate == unit => is_match(&message[unit.len_utf8()..], rules, stack),
+        _ => false,
+    }
+}
+
+fn match_sub_rules(
+    sub_rules: &[usize],
+    message: &str,
+    rules: &HashMap<usize, Rule>,
+    stack: &mut Vec<usize>,
+) -> bool {
+    sub_rules
+        .iter()
+        .rev()
+        .for_each(|rule_number| stack.push(*rule_number));
+
+    is_match(message, rules, stack)
+}
+
+pub fn is_match(message: &str, rules: &HashMap<usize, Rule>, stack: &mut Vec<usize>) -> bool {
+    if stack.is_empty() && message.is_empty() {
+        return true;
+    }
+    // Every rule left on the stack consumes at least one character, which stops
+    // left recursion. Rules that only alias each other never grow the stack, so
+    // parse_rules rejects them.
+    if stack.is_empty() || stack.len() > message.len() {
+        return false;
+    }
+
+    let rule_number = stack.pop().unwrap();
+    let rule = rules.get(&rule_number).unwrap();
+
+    match rule {
+        Rule::Unit(unit) => match_unit(*unit, message, rules, stack),
+        Rule::SubRules(sub_rules) => match_sub_rules(sub_rules, message, rules, stack),
+        Rule::Or(sub_rules_group1, sub_rules_group2) => {
+            match_sub_rules(sub_rules_group1, message, rules, &mut stack.clone())
+                || match_sub_rules(sub_rules_group2, message, rules, &mut stack.clone())
+        }
+    }
+}
+
+pub fn part1(rules: &HashMap<usize, Rule>, messages: &[&str]) -> usize {
+    messages
+        .iter()
+        .filter(|message| is_match(message, rules, &mut vec![0]))
+        .count()
+}
+
+pub fn part2(rules: &HashMap<usize, Rule>, messages: &[&str]) -> Result<usize> {
+    let mut rules = rules.clone();
+
+    if !rules.contains_key(&42) || !rules.contains_key(&31) {
+        return Err(Error::parse("Rules 42 and 31 are required"));
+    }
+
+    rules.insert(8, Rule::Or(vec![42], vec![42, 8]));
+    rules.insert(11, Rule::Or(vec![42, 31], vec![42, 11, 31]));
+    check_aliases(&rules)?;
+
+    Ok(part1(&rules, messages))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input<'a> = (HashMap<usize, Rule>, Vec<&'a str>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1((rules, messages): &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(part1(rules, messages))
+    }
+
+    fn part2((rules, messages): &Self::Input<'_>) -> Result<Self::Part2> {
+        part2(rules, messages)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(input: &str) -> Result<usize> {
+        parse(input).map(|(rules, messages)| part1(&rules, &messages))
+    }
+
+    #[test]
+    fn example() {
+        assert_eq!(count(include_str!("../input/test2.txt")), Ok(2));
+    }
+
+    #[test]
+    fn looping_rules() {
+        let (rules, messages) = parse(include_str!("../input/test3.txt")).unwrap();
+
+        assert_eq!(part1(&rules, &messages), 3);
+        assert_eq!(part2(&rules, &messages), Ok(12));
+    }
+
+    #[test]
+    fn is_match_consumes_the_whole_message() {
+        let (rules, _) = parse(include_str!("../input/test2.txt")).unwrap();
+
+        assert!(is_match("ababbb", &rules, &mut vec![0]));
+        assert!(!is_match("ababbba", &rules, &mut vec![0]));
+        assert!(!is_match("ababb", &rules, &mut vec![0]));
+    }
+
+    #[test]
+    fn left_recursive_rules_terminate() {
+        let input = include_str!("../input/test.txt");
+
+        assert_eq!(count(input), Ok(0));
+        assert_eq!(count("0: 0 1 | 1\n1: \"a\"\n\naaa\naab\n"), Ok(1));
+    }
+
+    #[test]
+    fn reports_undefined_rules() {
+        assert!(parse_rules("0: 1 2\n1: \"a\"\n").is_err());
+        assert_eq!(parse_rules("0: 1\n1: x\n").unwrap_err().line, Some(2));
+    }
+
+    #[test]
+    fn matches_multibyte_units() {
+        assert_eq!(count("0: 1 2\n1: \"é\"\n2: \"a\"\n\néa\nae\n"), Ok(1));
+    }
+
+    #[test]
+    fn rejects_rules_that_only_alias_each_other() {
+        let rules = "0: 1 | 2 3\n1: 4 | 0\n2: \"a\"\n3: \"b\"\n4: \"b\"\n";
+        let error = parse_rules(rules).unwrap_err();
+
+        assert_eq!(
+            error.message,
+            "Rules expand into each other without consuming input: 0 -> 1 -> 0"
+        );
+        assert!(count("0: 1\n1: 0\n\na\n").is_err());
+        assert!(count("0: 1 1\n1: 2 | 1\n2: \"a\"\n\naa\n").is_err());
+    }
+}
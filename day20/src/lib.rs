@@ -0,0 +1,510 @@
+use common::input::groups;
+use common::{parse_value, Context, Error, Result, Solution};
+use std::collections::HashMap;
+
+const MONSTER_1: usize = 18;
+const MONSTER_2: [usize; 8] = [0, 5, 6, 11, 12, 17, 18, 19];
+const MONSTER_3: [usize; 6] = [1, 4, 7, 10, 13, 16];
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Tile {
+    points: Vec<Vec<char>>,
+    width: usize,
+    height: usize,
+    transformations: Option<Vec<Tile>>,
+}
+
+impl Tile {
+    pub fn new<'a, I>(lines: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let points: Vec<Vec<_>> = lines.into_iter().map(|l| l.chars().collect()).collect();
+        let height = points.len();
+
+        if height < 3 {
+            return Err(Error::parse("Tiles must have at least three rows"));
+        }
+
+        for (row, line) in points.iter().enumerate() {
+            if line.len() != height {
+                return Err(Error::parse(format!(
+                    "Expected {} pixels, found {}",
+                    height,
+                    line.len()
+                ))
+                .at_line(row + 1));
+            }
+
+            if let Some(column) = line.iter().position(|&c| c != '#' && c != '.') {
+                return Err(Error::parse(format!("Invalid pixel '{}'", line[column]))
+                    .at_line(row + 1)
+                    .at_column(column + 1));
+            }
+        }
+
+        let width = height;
+        let transformations: Vec<Tile> =
+            TransformationIterator::new(points.clone(), width, height).collect();
+
+        Ok(Self {
+            points,
+            width,
+            height,
+            transformations: Some(transformations),
+        })
+    }
+
+    pub fn get_row_without_borders(&self, row: usize) -> Vec<char> {
+        self.points[row][1..self.width - 1].to_vec()
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> char {
+        self.points[row][column]
+    }
+
+    pub fn top_border(&self) -> Vec<char> {
+        (0..self.height).map(|c| self.get(0, c)).collect()
+    }
+
+    pub fn bottom_border(&self) -> Vec<char> {
+        (0..self.height)
+            .map(|c| self.get(self.height - 1, c))
+            .collect()
+    }
+
+    pub fn left_border(&self) -> Vec<char> {
+        (0..self.width).map(|r| self.get(r, 0)).collect()
+    }
+
+    pub fn right_border(&self) -> Vec<char> {
+        (0..self.width)
+            .map(|r| self.get(r, self.width - 1))
+            .collect()
+    }
+}
+
+pub struct TransformationIterator {
+    points: Vec<Vec<char>>,
+    width: usize,
+    height: usize,
+    rotations: usize,
+    flipped_rotations: usize,
+}
+
+impl TransformationIterator {
+    pub fn rotate_left(&mut self) {
+        let mut rotation = self.points.clone();
+        let max_idx = self.width - 1;
+
+        for (row, points) in self.points.iter().enumerate() {
+            for (column, &x) in points.iter().enumerate() {
+                rotation[max_idx - column][row] = x;
+            }
+        }
+
+        self.points = rotation;
+    }
+
+    fn flip_horizontal(&mut self) {
+        self.points = self
+            .points
+            .iter()
+            .map(|r| {
+                let mut row = r.clone();
+                row.reverse();
+                row
+            })
+            .collect();
+    }
+
+    pub fn new(points: Vec<Vec<char>>, width: usize, height: usize) -> Self {
+        Self {
+            points,
+            width,
+            height,
+            rotations: 4,
+            flipped_rotations: 4,
+        }
+    }
+}
+
+impl Iterator for TransformationIterator {
+    type Item = Tile;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rotations == 4 {
+            self.rotations -= 1;
+            return Some(Tile {
+                points: self.points.clone(),
+                width: self.width,
+                height: self.height,
+                transformations: None,
+            });
+        }
+
+        if self.rotations > 0 {
+            self.rotate_left();
+            self.rotations -= 1;
+            return Some(Tile {
+                points: self.points.clone(),
+                width: self.width,
+                height: self.height,
+                transformations: None,
+            });
+        }
+
+        if self.flipped_rotations == 4 {
+            self.flip_horizontal();
+            self.flipped_rotations -= 1;
+            return Some(Tile {
+                points: self.points.clone(),
+                width: self.width,
+                height: self.height,
+                transformations: None,
+            });
+        }
+
+        if self.flipped_rotations > 0 {
+            self.rotate_left();
+            self.flipped_rotations -= 1;
+            return Some(Tile {
+                points: self.points.clone(),
+                width: self.width,
+                height: self.height,
+                transformations: None,
+            });
+        }
+
+        None
+    }
+}
+
+pub fn parse(input: &str) -> Result<HashMap<usize, Tile>> {
+    let mut tilemap = HashMap::new();
+
+    for (line, group) in groups(input) {
+        let mut lines = group.lines();
+
+        let header = lines.next().unwrap_or("");
+        let tile_id = match header
+            .strip_prefix("Tile ")
+            .and_then(|s| s.strip_suffix(':'))
+        {
+            Some(id) => parse_value::<usize>(id).at_line(line).at_column(6)?,
+            None => {
+                return Err(Error::parse(format!("Invalid tile header '{}'", header)).at_line(line))
+            }
+        };
+
+        // Errors inside the tile are relative to its first row, just below the header.
+        let tile = Tile::new(lines).offset_line(line)?;
+
+        if tilemap
+            .values()
+            .any(|other: &Tile| other.height != tile.height)
+        {
+            return Err(Error::parse("Tiles must all have the same size").at_line(line));
+        }
+
+        if tilemap.insert(tile_id, tile).is_some() {
+            return Err(Error::parse(format!("Duplicate tile {}", tile_id)).at_line(line));
+        }
+    }
+
+    let side = (tilemap.len() as f64).sqrt() as usize;
+
+    if side * side != tilemap.len() {
+        return Err(Error::parse(format!(
+            "Expected a square number of tiles, found {}",
+            tilemap.len()
+        )));
+    }
+
+    Ok(tilemap)
+}
+
+fn find_top_left(tilemap: &HashMap<usize, Tile>) -> Result<(usize, Tile)> {
+    for (id, tile) in tilemap {
+        for transformation in tile.transformations.clone().unwrap().iter() {
+            let mut right_matches_amount = 0;
+            let mut bottom_matches_amount = 0;
+
+            let left_border = transformation.left_border();
+            let top_border = transformation.top_border();
+
+            for (other_id, other_tile) in tilemap {
+                if id == other_id {
+                    continue;
+                }
+
+                other_tile
+                    .transformations
+                    .clone()
+                    .unwrap()
+                    .iter()
+                    .for_each(|t| {
+                        if t.right_border() == left_border {
+                            right_matches_amount += 1;
+                        }
+
+                        if t.bottom_border() == top_border {
+                            bottom_matches_amount += 1;
+                        }
+                    })
+            }
+
+            if right_matches_amount == 0 && bottom_matches_amount == 0 {
+                return Ok((*id, transformation.clone()));
+            }
+        }
+    }
+
+    Err(Error::no_solution("Top left not found"))
+}
+
+fn find_first_column(
+    top_tile_id: usize,
+    top_tile: &Tile,
+    tilemap: &mut HashMap<usize, Tile>,
+    rows: usize,
+) -> Vec<(usize, Tile)> {
+    let current_tilemap = tilemap.clone();
+    let mut candidates: Vec<(usize, Tile)> = vec![(top_tile_id, top_tile.clone())];
+    let mut bottom_border: Vec<char> = top_tile.bottom_border();
+
+    for _ in 0..rows {
+        'inner: for (id, tile) in &current_tilemap {
+            for transformation in tile.transformations.clone().unwrap().iter() {
+                if !tilemap.contains_key(id) {
+                    continue;
+                }
+
+                if transformation.top_border() == bottom_border {
+                    tilemap.remove(id);
+                    bottom_border = transformation.bottom_border();
+                    candidates.push((*id, transformation.clone()));
+                    break 'inner;
+                }
+            }
+        }
+    }
+
+    candidates
+}
+
+fn find_row(
+    left_tile: &Tile,
+    tilemap: &mut HashMap<usize, Tile>,
+    columns: usize,
+) -> Vec<(usize, Tile)> {
+    let current_tilemap = tilemap.clone();
+    let mut entries: Vec<(usize, Tile)> = Vec::new();
+    let mut right_border: Vec<char> = left_tile.right_border();
+
+    for _ in 0..columns {
+        'inner: for (id, tile) in &current_tilemap {
+            for transformation in tile.transformations.clone().unwrap().iter() {
+                if !tilemap.contains_key(id) {
+                    continue;
+                }
+
+                if transformation.left_border() == right_border {
+                    tilemap.remove(id);
+                    right_border = transformation.right_border();
+                    entries.push((*id, transformation.clone()));
+                    break 'inner;
+                }
+            }
+        }
+    }
+
+    entries
+}
+
+pub fn find_image(tilemap: &mut HashMap<usize, Tile>) -> Result<(Vec<Vec<usize>>, Tile)> {
+    let tiles_amount = (tilemap.keys().len() as f64).sqrt() as usize;
+
+    let mut image: Vec<Vec<(usize, Tile)>> = (0..tiles_amount).map(|_| Vec::new()).collect();
+    let mut current_row = 0;
+
+    let (top_left_id, top_left_tile) = find_top_left(tilemap)?;
+    tilemap.remove(&top_left_id);
+
+    find_first_column(top_left_id, &top_left_tile, tilemap, tiles_amount - 1)
+        .iter()
+        .for_each(|(id, left_tile)| {
+            image[current_row].push((*id, left_tile.clone()));
+
+            find_row(left_tile, tilemap, tiles_amount - 1)
+                .iter()
+                .for_each(|(id, tile)| {
+                    image[current_row].push((*id, tile.clone()));
+                });
+
+            current_row += 1;
+        });
+
+    if image.iter().any(|row| row.len() != tiles_amount) {
+        return Err(Error::no_solution("Tiles do not form a complete image"));
+    }
+
+    let tile_composition = image
+        .iter()
+        .map(|row| row.iter().map(|r| r.0).collect::<Vec<usize>>())
+        .collect::<Vec<Vec<usize>>>();
+
+    let tile_rows = image[0][0].1.height - 2;
+    let mut final_image: Vec<Vec<char>> =
+        (0..tiles_amount * tile_rows).map(|_| Vec::new()).collect();
+
+    for (row_idx, image_row) in image.iter().enumerate() {
+        for (_, tile) in image_row {
+            for tile_row in 1..=tile_rows {
+                final_image[(row_idx * tile_rows) + tile_row - 1]
+                    .append(&mut tile.get_row_without_borders(tile_row));
+            }
+        }
+    }
+
+    let final_height = final_image.len();
+    let final_width = final_image[0].len();
+
+    Ok((
+        tile_composition,
+        Tile {
+            points: final_image,
+            height: final_height,
+            width: final_width,
+            transformations: None,
+        },
+    ))
+}
+
+fn monsters_in(tile: &Tile) -> usize {
+    let mut count = 0;
+
+    for rows in tile.points.windows(3) {
+        let mut rows_iter = rows.iter();
+        let first_row = rows_iter.next().unwrap();
+        let second_row = rows_iter.next().unwrap();
+        let third_row = rows_iter.next().unwrap();
+
+        let candidates = first_row.len().saturating_sub(MONSTER_2[7]);
+
+        for (idx_1, point) in first_row
+            .iter()
+            .skip(MONSTER_1)
+            .take(candidates)
+            .enumerate()
+        {
+            if *point != '#' {
+                continue;
+            }
+
+            if MONSTER_2.iter().all(|x| second_row[x + idx_1] == '#')
+                && MONSTER_3.iter().all(|x| third_row[x + idx_1] == '#')
+            {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+pub fn count_monsters(tile: &Tile) -> usize {
+    for transformation in TransformationIterator::new(tile.points.clone(), tile.width, tile.height)
+    {
+        let monsters = monsters_in(&transformation);
+
+        if monsters != 0 {
+            return monsters;
+        }
+    }
+
+    0
+}
+
+pub fn part1(tile_composition: &[Vec<usize>]) -> usize {
+    let side = tile_composition[0].len() - 1;
+
+    tile_composition[0][0]
+        * tile_composition[0][side]
+        * tile_composition[side][0]
+        * tile_composition[side][side]
+}
+
+pub fn part2(image: &Tile) -> usize {
+    let monsters = count_monsters(image);
+    let tags_in_monster = 15;
+    let mut total_tags = 0;
+
+    for row in 0..image.height {
+        for column in 0..image.width {
+            if image.get(row, column) == '#' {
+                total_tags += 1;
+            }
+        }
+    }
+
+    total_tags - (monsters * tags_in_monster)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input<'a> = (Vec<Vec<usize>>, Tile);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let mut tilemap = parse(input)?;
+        find_image(&mut tilemap)
+    }
+
+    fn part1((tile_composition, _): &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(part1(tile_composition))
+    }
+
+    fn part2((_, image): &Self::Input<'_>) -> Result<Self::Part2> {
+        Ok(part2(image))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn example() {
+        let mut tilemap = parse(include_str!("../input/test.txt")).unwrap();
+        let (tile_composition, image) = find_image(&mut tilemap).unwrap();
+
+        assert_eq!(part1(&tile_composition), 20899048083289);
+        assert_eq!(count_monsters(&image), 2);
+        assert_eq!(part2(&image), 273);
+    }
+
+    #[test]
+    fn transformations_cover_rotations_and_flips() {
+        let tile = Tile::new(vec!["#..", "...", "..."]).unwrap();
+        let corners: HashSet<(usize, usize)> = TransformationIterator::new(tile.points, 3, 3)
+            .map(|t| {
+                let row = (0..3).find(|&r| t.points[r].contains(&'#')).unwrap();
+                (row, t.points[row].iter().position(|&c| c == '#').unwrap())
+            })
+            .collect();
+
+        assert_eq!(corners.len(), 4);
+    }
+
+    #[test]
+    fn reports_invalid_tiles() {
+        assert_eq!(parse("Tile 1:\n#..\n.x.\n...\n").unwrap_err().line, Some(3));
+        assert!(parse("Tile x:\n#..\n...\n...\n").is_err());
+    }
+}
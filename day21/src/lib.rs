@@ -0,0 +1,164 @@
+use common::{Error, Result, Solution};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+#[macro_use]
+extern crate lazy_static;
+
+lazy_static! {
+    static ref CONTAINS_RE: Regex = Regex::new(r"^(.*) \(contains (.*)\)$").unwrap();
+}
+
+pub type Food<'a> = (HashMap<&'a str, usize>, HashMap<&'a str, HashSet<&'a str>>);
+
+pub fn parse(input: &str) -> Result<Food<'_>> {
+    let mut ingredients_frequency = HashMap::new();
+    let mut allergens_to_ingredients: HashMap<&str, HashSet<&str>> = HashMap::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let matches = CONTAINS_RE.captures(line).ok_or_else(|| {
+            Error::parse(format!(
+                "Expected '<ingredients> (contains <allergens>)', found '{}'",
+                line
+            ))
+            .at_line(index + 1)
+        })?;
+        let ingredients = matches
+            .get(1)
+            .unwrap()
+            .as_str()
+            .split(' ')
+            .collect::<HashSet<&str>>();
+
+        let allergens = matches
+            .get(2)
+            .unwrap()
+            .as_str()
+            .split(", ")
+            .collect::<Vec<&str>>();
+
+        ingredients
+            .iter()
+            .for_each(|ingredient| *ingredients_frequency.entry(*ingredient).or_insert(0) += 1);
+
+        for allergen in allergens {
+            match allergens_to_ingredients.get(allergen) {
+                Some(ingredients_per_allergen) => allergens_to_ingredients.insert(
+                    allergen,
+                    ingredients_per_allergen
+                        .to_owned()
+                        .intersection(&ingredients)
+                        .copied()
+                        .collect(),
+                ),
+                None => allergens_to_ingredients.insert(allergen, ingredients.clone()),
+            };
+        }
+    }
+
+    Ok((ingredients_frequency, allergens_to_ingredients))
+}
+
+pub fn part1(
+    ingredients_frequency: &HashMap<&str, usize>,
+    allergens_to_ingredients: &HashMap<&str, HashSet<&str>>,
+) -> usize {
+    ingredients_frequency
+        .iter()
+        .filter(|&(ingredient, _)| {
+            !allergens_to_ingredients
+                .values()
+                .any(|ingredients_per_allergen| ingredients_per_allergen.contains(ingredient))
+        })
+        .map(|(_, frequency)| *frequency)
+        .sum()
+}
+
+fn allergen_with_multiple_ingredients(
+    allergens_to_ingredients: &HashMap<&str, HashSet<&str>>,
+) -> bool {
+    allergens_to_ingredients
+        .values()
+        .any(|ingredients| ingredients.len() > 1)
+}
+
+pub fn part2(mut allergens_to_ingredients: HashMap<&str, HashSet<&str>>) -> Result<String> {
+    while allergen_with_multiple_ingredients(&allergens_to_ingredients) {
+        let known_ingredients: HashSet<&str> = allergens_to_ingredients
+            .values()
+            .filter(|set| set.len() == 1)
+            .flatten()
+            .copied()
+            .collect();
+
+        if known_ingredients.is_empty() {
+            return Err(Error::no_solution("Allergens are ambiguous"));
+        }
+
+        allergens_to_ingredients = allergens_to_ingredients
+            .into_iter()
+            .map(|(i, s)| match s.len() {
+                1 => (i, s),
+                _ => (i, s.difference(&known_ingredients).copied().collect()),
+            })
+            .collect();
+    }
+
+    let mut matches = allergens_to_ingredients
+        .iter()
+        .map(|(k, v)| match v.iter().next() {
+            Some(ingredient) => Ok((k, ingredient)),
+            None => Err(Error::no_solution(format!("No ingredient contains {}", k))),
+        })
+        .collect::<Result<Vec<(&&str, &&str)>>>()?;
+
+    matches.sort();
+
+    Ok(matches
+        .iter()
+        .map(|(_, ingredient)| **ingredient)
+        .collect::<Vec<&str>>()
+        .join(","))
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input<'a> = Food<'a>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1(
+        (ingredients_frequency, allergens_to_ingredients): &Self::Input<'_>,
+    ) -> Result<Self::Part1> {
+        Ok(part1(ingredients_frequency, allergens_to_ingredients))
+    }
+
+    fn part2((_, allergens_to_ingredients): &Self::Input<'_>) -> Result<Self::Part2> {
+        part2(allergens_to_ingredients.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (ingredients, allergens) = parse(include_str!("../input/test.txt")).unwrap();
+
+        assert_eq!(part1(&ingredients, &allergens), 5);
+        assert_eq!(part2(allergens), Ok(String::from("mxmxvkd,sqjhc,fvjkl")));
+    }
+
+    #[test]
+    fn reports_invalid_foods() {
+        assert_eq!(parse("a b (contains c)\na b\n").unwrap_err().line, Some(2));
+    }
+}
@@ -0,0 +1,172 @@
+use common::input::groups;
+use common::{parse_value, Context, Error, Result, Solution};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
+
+pub fn parse_player(input: &str) -> Result<VecDeque<u32>> {
+    let mut lines = input.lines();
+
+    match lines.next() {
+        Some(header) if header.starts_with("Player ") && header.ends_with(':') => (),
+        header => {
+            return Err(
+                Error::parse(format!("Invalid player header '{}'", header.unwrap_or("")))
+                    .at_line(1),
+            )
+        }
+    }
+
+    lines
+        .enumerate()
+        .map(|(index, line)| parse_value(line).at_line(index + 2))
+        .collect()
+}
+
+pub fn parse(input: &str) -> Result<(VecDeque<u32>, VecDeque<u32>)> {
+    let (player_1, player_2) = match groups(input).collect::<Vec<_>>().as_slice() {
+        [(first_line_1, player_1), (first_line_2, player_2)] => (
+            parse_player(player_1).offset_line(first_line_1 - 1)?,
+            parse_player(player_2).offset_line(first_line_2 - 1)?,
+        ),
+        _ => return Err(Error::parse("Expected two decks separated by a blank line")),
+    };
+
+    let mut seen = HashSet::new();
+
+    if let Some(card) = player_1
+        .iter()
+        .chain(player_2.iter())
+        .find(|&&card| !seen.insert(card))
+    {
+        return Err(Error::parse(format!(
+            "Card {} appears more than once",
+            card
+        )));
+    }
+
+    Ok((player_1, player_2))
+}
+
+pub fn part1(mut player_1: VecDeque<u32>, mut player_2: VecDeque<u32>) -> VecDeque<u32> {
+    while !player_1.is_empty() && !player_2.is_empty() {
+        let c1 = player_1.pop_front().unwrap();
+        let c2 = player_2.pop_front().unwrap();
+
+        if c1 > c2 {
+            player_1.push_back(c1);
+            player_1.push_back(c2);
+        } else {
+            player_2.push_back(c2);
+            player_2.push_back(c1);
+        }
+    }
+
+    match player_1.is_empty() {
+        false => player_1,
+        true => player_2,
+    }
+}
+
+pub fn part2(player_1: &mut VecDeque<u32>, player_2: &mut VecDeque<u32>) -> bool {
+    let mut seen = HashSet::new();
+
+    while !player_1.is_empty() && !player_2.is_empty() {
+        let mut hasher = DefaultHasher::new();
+        player_1.hash(&mut hasher);
+        player_2.hash(&mut hasher);
+
+        if !seen.insert(hasher.finish()) {
+            return true;
+        }
+
+        let c1 = player_1.pop_front().unwrap();
+        let c2 = player_2.pop_front().unwrap();
+
+        let player_1_win = if player_1.len() as u32 >= c1 && player_2.len() as u32 >= c2 {
+            let mut new_one = player_1.clone();
+            let mut new_two = player_2.clone();
+            new_one.truncate(c1 as _);
+            new_two.truncate(c2 as _);
+            part2(&mut new_one, &mut new_two)
+        } else {
+            c1 > c2
+        };
+
+        if player_1_win {
+            player_1.push_back(c1);
+            player_1.push_back(c2);
+        } else {
+            player_2.push_back(c2);
+            player_2.push_back(c1);
+        }
+    }
+
+    player_2.is_empty()
+}
+
+pub fn score(mut cards: VecDeque<u32>) -> u32 {
+    (1..=cards.len() as u32)
+        .rev()
+        .map(|weight| weight * cards.pop_front().unwrap())
+        .sum()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Input<'a> = (VecDeque<u32>, VecDeque<u32>);
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse(input)
+    }
+
+    fn part1((player_1, player_2): &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(score(part1(player_1.clone(), player_2.clone())))
+    }
+
+    fn part2((player_1, player_2): &Self::Input<'_>) -> Result<Self::Part2> {
+        let (mut player_1, mut player_2) = (player_1.clone(), player_2.clone());
+
+        if part2(&mut player_1, &mut player_2) {
+            Ok(score(player_1))
+        } else {
+            Ok(score(player_2))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let (player_1, player_2) = parse(include_str!("../input/test.txt")).unwrap();
+
+        assert_eq!(score(part1(player_1.clone(), player_2.clone())), 306);
+        assert_eq!(Day22::part2(&(player_1, player_2)), Ok(291));
+    }
+
+    #[test]
+    fn recursive_game_terminates() {
+        let (mut player_1, mut player_2) =
+            parse("Player 1:\n43\n19\n\nPlayer 2:\n2\n29\n14\n").unwrap();
+
+        assert!(part2(&mut player_1, &mut player_2));
+    }
+
+    #[test]
+    fn reports_invalid_decks() {
+        assert_eq!(
+            parse("Player 1:\n1\n\nPlayer 2:\nx\n").unwrap_err().line,
+            Some(5)
+        );
+        assert!(parse("Player 1:\n1\n\nPlayer 2:\n1\n").is_err());
+    }
+}
@@ -0,0 +1,159 @@
+use common::{Error, Result, Solution};
+use std::collections::VecDeque;
+
+pub fn parse_cups(input: &str) -> Result<Vec<usize>> {
+    let line = input.trim();
+    let mut cups = Vec::new();
+
+    for (column, c) in line.chars().enumerate() {
+        match c.to_digit(10) {
+            Some(cup) if cup > 0 => cups.push(cup as usize),
+            _ => return Err(Error::parse(format!("Invalid cup '{}'", c)).at_column(column + 1)),
+        }
+    }
+
+    let mut sorted = cups.clone();
+    sorted.sort_unstable();
+
+    if sorted.len() < 5 || sorted.iter().enumerate().any(|(i, &cup)| cup != i + 1) {
+        return Err(Error::parse(format!(
+            "Expected cups labeled 1 to N with N of at least 5, found '{}'",
+            line
+        )));
+    }
+
+    Ok(cups)
+}
+
+pub fn part1(cups: &[usize]) -> String {
+    let mut cups: VecDeque<usize> = cups.iter().copied().collect();
+
+    let cups_len = cups.len();
+
+    for _ in 0..100 {
+        let current = cups.pop_front().unwrap();
+        let next_three = cups.drain(..3).collect::<Vec<usize>>();
+        cups.push_back(current);
+
+        let mut order: Vec<(usize, usize)> = cups
+            .iter()
+            .map(|&value| {
+                if value > current {
+                    current + cups_len - value
+                } else {
+                    current - value
+                }
+            })
+            .enumerate()
+            .collect();
+
+        order.sort_by_key(|(_, a)| *a);
+        let (destination, _) = order.get(1).unwrap();
+
+        (0..3).for_each(|index| cups.insert(destination + index + 1, next_three[index]));
+    }
+
+    let one_position = cups.iter().position(|&value| value == 1).unwrap();
+    cups.rotate_left(one_position);
+
+    cups.iter().skip(1).map(|value| value.to_string()).collect()
+}
+
+pub fn link(cups: &[usize]) -> (Vec<usize>, usize) {
+    let mut numbers: Vec<usize> = cups.to_vec();
+
+    (numbers.len() + 1..=1_000_000).for_each(|value| numbers.push(value));
+
+    let mut link_list = vec![0; numbers.len() + 1];
+    for i in 0..numbers.len() {
+        link_list[numbers[i]] = numbers[(i + 1) % numbers.len()];
+    }
+
+    (link_list, numbers[0])
+}
+
+pub fn part2(mut link_list: Vec<usize>, start: usize) -> usize {
+    let mut current_value = start;
+    let max_deck = link_list.len() - 1;
+
+    for _ in 0..10_000_000 {
+        let mut next_three = [0; 3];
+
+        let mut value_next = current_value;
+        for next in next_three.iter_mut() {
+            value_next = link_list[value_next];
+            *next = value_next;
+        }
+
+        link_list[current_value] = link_list[next_three[2]];
+        let mut destination = current_value;
+
+        loop {
+            destination = if destination > 1 {
+                destination - 1
+            } else {
+                max_deck
+            };
+            if !next_three.contains(&destination) {
+                break;
+            }
+        }
+
+        let old_next = link_list[destination];
+        link_list[destination] = next_three[0];
+        link_list[next_three[2]] = old_next;
+
+        current_value = link_list[current_value];
+    }
+
+    let after_one = link_list[1];
+    let next = link_list[after_one];
+    after_one * next
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input<'a> = Vec<usize>;
+    type Part1 = String;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        parse_cups(input)
+    }
+
+    fn part1(cups: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(part1(cups))
+    }
+
+    fn part2(cups: &Self::Input<'_>) -> Result<Self::Part2> {
+        let (link_list, start) = link(cups);
+        Ok(part2(link_list, start))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input/test.txt");
+
+    #[test]
+    fn example() {
+        let cups = parse_cups(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&cups), "67384529");
+
+        let (link_list, start) = link(&cups);
+        assert_eq!(part2(link_list, start), 149245887792);
+    }
+
+    #[test]
+    fn reports_invalid_cups() {
+        assert_eq!(parse_cups("38912546x").unwrap_err().column, Some(9));
+        assert!(parse_cups("3891254").is_err());
+        assert!(parse_cups("1234").is_err());
+    }
+}
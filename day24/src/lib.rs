@@ -0,0 +1,138 @@
+use automaton::topology::hex;
+use automaton::{Automaton, Rule, Sparse};
+use common::{Error, Result, Solution};
+use std::collections::HashSet;
+
+const DIRECTIONS: [&str; 6] = ["ne", "nw", "se", "sw", "e", "w"];
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct Tile {
+    x: i32,
+    y: i32,
+}
+
+impl Tile {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    pub fn step(&mut self, direction: &str) -> Result<()> {
+        let direction = match direction {
+            "se" => (1, -1),
+            "sw" => (0, -1),
+            "ne" => (0, 1),
+            "nw" => (-1, 1),
+            "e" => (1, 0),
+            "w" => (-1, 0),
+            _ => return Err(Error::parse(format!("Invalid direction '{}'", direction))),
+        };
+
+        self.x += direction.0;
+        self.y += direction.1;
+        Ok(())
+    }
+
+    pub fn neighbours(&self) -> Vec<Tile> {
+        hex(&(self.x, self.y))
+            .into_iter()
+            .map(|(x, y)| Tile::new(x, y))
+            .collect()
+    }
+}
+
+// Returns the tiles left black side up.
+pub fn flip_tiles(input: &str) -> Result<HashSet<Tile>> {
+    let mut black: HashSet<Tile> = HashSet::new();
+
+    for (index, full_line) in input.lines().enumerate() {
+        let mut tile = Tile::new(0, 0);
+        let mut line = full_line;
+
+        while !line.is_empty() {
+            let column = full_line.len() - line.len() + 1;
+
+            match DIRECTIONS
+                .iter()
+                .find(|direction| line.starts_with(*direction))
+            {
+                Some(direction) => {
+                    line = &line[direction.len()..];
+                    tile.step(direction)?;
+                }
+                None => {
+                    return Err(Error::parse(format!("Invalid direction in '{}'", line))
+                        .at_line(index + 1)
+                        .at_column(column))
+                }
+            }
+        }
+
+        if !black.remove(&tile) {
+            black.insert(tile);
+        }
+    }
+
+    Ok(black)
+}
+
+pub fn exhibit(black: &HashSet<Tile>, days: usize) -> usize {
+    let mut floor = Sparse::new(
+        black.iter().copied(),
+        Tile::neighbours,
+        Rule::new(&[2], &[1, 2]),
+    );
+
+    floor.step_n(days);
+    floor.population()
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input<'a> = HashSet<Tile>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        flip_tiles(input)
+    }
+
+    fn part1(black: &Self::Input<'_>) -> Result<Self::Part1> {
+        Ok(black.len())
+    }
+
+    fn part2(black: &Self::Input<'_>) -> Result<Self::Part2> {
+        Ok(exhibit(black, 100))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let tiles = flip_tiles(include_str!("../input/test.txt")).unwrap();
+
+        assert_eq!(tiles.len(), 10);
+        assert_eq!(exhibit(&tiles, 1), 15);
+        assert_eq!(exhibit(&tiles, 100), 2208);
+    }
+
+    #[test]
+    fn steps_back_to_the_reference_tile() {
+        let tiles = flip_tiles("nwwswee\n").unwrap();
+
+        assert!(tiles.contains(&Tile::new(0, 0)));
+    }
+
+    #[test]
+    fn reports_invalid_directions() {
+        let error = flip_tiles("esew\nenx\n").unwrap_err();
+
+        assert_eq!(error.line, Some(2));
+        assert_eq!(error.column, Some(2));
+    }
+}
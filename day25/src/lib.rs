@@ -0,0 +1,75 @@
+use common::{parse_value, Context, Error, Result, Solution};
+use math::{discrete_log, mod_pow};
+
+const MODULUS: u64 = 20201227;
+
+pub fn get_encryption_key(card_loop_size: u64, door_public_key: u64) -> Result<u64> {
+    mod_pow(door_public_key, card_loop_size, MODULUS).ok_or_else(|| {
+        Error::no_solution(format!(
+            "No encryption key for public key {}",
+            door_public_key
+        ))
+    })
+}
+
+pub fn crack_loop_size(public_key: u64) -> Result<u64> {
+    let loop_size = match public_key < MODULUS {
+        true => discrete_log(7, public_key, MODULUS),
+        false => None,
+    };
+
+    loop_size.ok_or_else(|| {
+        Error::no_solution(format!("No loop size produces public key {}", public_key))
+    })
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input<'a> = (u64, u64);
+    type Part1 = u64;
+    type Part2 = &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>> {
+        let mut lines = input.lines();
+        let mut public_key = |line: usize| match lines.next() {
+            Some(key) => parse_value::<u64>(key).at_line(line),
+            None => Err(Error::parse("Missing public key").at_line(line)),
+        };
+
+        let card_public_key = public_key(1)?;
+        let door_public_key = public_key(2)?;
+
+        Ok((card_public_key, door_public_key))
+    }
+
+    fn part1(&(card_public_key, door_public_key): &Self::Input<'_>) -> Result<Self::Part1> {
+        let card_loop_size = crack_loop_size(card_public_key)?;
+        get_encryption_key(card_loop_size, door_public_key)
+    }
+
+    fn part2(_: &Self::Input<'_>) -> Result<Self::Part2> {
+        Ok("Merry Christmas!")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        assert_eq!(crack_loop_size(5764801), Ok(8));
+        assert_eq!(crack_loop_size(17807724), Ok(11));
+        assert_eq!(get_encryption_key(8, 17807724), Ok(14897079));
+        assert_eq!(get_encryption_key(11, 5764801), Ok(14897079));
+    }
+
+    #[test]
+    fn reports_unreachable_keys() {
+        assert!(crack_loop_size(0).is_err());
+        assert!(Day25::parse("5764801\n").is_err());
+    }
+}
@@ -0,0 +1,229 @@
+use num_integer::{Integer, Roots};
+use num_traits::Signed;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Every function works on any integer type, but products of two residues must
+// fit in `T`: moduli above 2^32 need i128 or a bigint rather than u64.
+
+fn modulo<T: Integer + Clone>(value: T, modulus: &T) -> T {
+    (value % modulus.clone() + modulus.clone()) % modulus.clone()
+}
+
+fn sub_mod<T: Integer + Clone>(a: T, b: T, modulus: &T) -> T {
+    (a + modulus.clone() - b) % modulus.clone()
+}
+
+fn mul_mod<T: Integer + Clone>(a: T, b: T, modulus: &T) -> T {
+    a * b % modulus.clone()
+}
+
+// Returns `(g, x, y)` such that `a * x + b * y == g == gcd(a, b)`.
+pub fn egcd<T: Integer + Signed + Clone>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+
+    while !r.is_zero() {
+        let q = old_r.clone() / r.clone();
+
+        (old_r, r) = (r.clone(), old_r - q.clone() * r);
+        (old_x, x) = (x.clone(), old_x - q.clone() * x);
+        (old_y, y) = (y.clone(), old_y - q * y);
+    }
+
+    (old_r, old_x, old_y)
+}
+
+// Coefficients are kept in `0..n`, so unsigned types work too.
+pub fn mod_inv<T: Integer + Clone>(a: T, n: T) -> Option<T> {
+    if n.is_zero() {
+        return None;
+    }
+
+    let (mut old_r, mut r) = (n.clone(), modulo(a, &n));
+    let (mut old_t, mut t) = (T::zero(), T::one());
+
+    while !r.is_zero() {
+        let q = old_r.clone() / r.clone();
+
+        (old_r, r) = (r.clone(), old_r - q.clone() * r);
+        (old_t, t) = (t.clone(), sub_mod(old_t, mul_mod(q, t, &n), &n));
+    }
+
+    match old_r.is_one() {
+        true => Some(old_t),
+        false => None,
+    }
+}
+
+// Negative exponents raise the inverse of `base`, so they return `None` when
+// `base` is not invertible. A zero modulus also returns `None`.
+pub fn mod_pow<T: Integer + Clone>(base: T, exponent: T, modulus: T) -> Option<T> {
+    if modulus.is_zero() {
+        return None;
+    }
+
+    let two = T::one() + T::one();
+    let (mut base, mut exponent) = match exponent < T::zero() {
+        true => (mod_inv(base, modulus.clone())?, T::zero() - exponent),
+        false => (modulo(base, &modulus), exponent),
+    };
+    let mut result = T::one() % modulus.clone();
+
+    while !exponent.is_zero() {
+        if exponent.is_odd() {
+            result = mul_mod(result, base.clone(), &modulus);
+        }
+
+        base = mul_mod(base.clone(), base, &modulus);
+        exponent = exponent / two.clone();
+    }
+
+    Some(result)
+}
+
+// Solves `x ≡ residue (mod modulus)` for every `(residue, modulus)` pair. Moduli
+// don't need to be coprime; the result is `(x, lcm)` with `x` in `0..lcm`, or
+// `None` when the congruences contradict each other or a modulus is zero.
+pub fn chinese_remainder<T: Integer + Clone>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    congruences
+        .iter()
+        .try_fold((T::zero(), T::one()), |(x, lcm), (residue, modulus)| {
+            if modulus.is_zero() {
+                return None;
+            }
+
+            let gcd = lcm.gcd(modulus);
+            let difference = sub_mod(
+                modulo(residue.clone(), modulus),
+                x.clone() % modulus.clone(),
+                modulus,
+            );
+
+            if !(difference.clone() % gcd.clone()).is_zero() {
+                return None;
+            }
+
+            let step = modulus.clone() / gcd.clone();
+            let inverse = mod_inv(lcm.clone() / gcd.clone(), step.clone())?;
+            let k = mul_mod(difference / gcd % step.clone(), inverse, &step);
+
+            Some((x + lcm.clone() * k, lcm * step))
+        })
+}
+
+// Smallest `x` with `base^x ≡ target (mod modulus)`, using baby-step giant-step.
+// `base` must be invertible modulo `modulus`, which must be positive.
+pub fn discrete_log<T>(base: T, target: T, modulus: T) -> Option<T>
+where
+    T: Integer + Roots + Hash + Clone,
+{
+    if modulus <= T::zero() {
+        return None;
+    }
+
+    let base = modulo(base, &modulus);
+    let target = modulo(target, &modulus);
+    let steps = modulus.sqrt() + T::one();
+    let mut baby_steps: HashMap<T, T> = HashMap::new();
+    let mut value = T::one() % modulus.clone();
+    let mut j = T::zero();
+
+    while j < steps {
+        baby_steps.entry(value.clone()).or_insert_with(|| j.clone());
+        value = mul_mod(value, base.clone(), &modulus);
+        j = j + T::one();
+    }
+
+    let giant_step = mod_inv(
+        mod_pow(base, steps.clone(), modulus.clone())?,
+        modulus.clone(),
+    )?;
+    let mut value = target;
+    let mut i = T::zero();
+
+    while i < steps {
+        if let Some(j) = baby_steps.get(&value) {
+            return Some(i * steps + j.clone());
+        }
+
+        value = mul_mod(value, giant_step.clone(), &modulus);
+        i = i + T::one();
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn extended_gcd() {
+        assert_eq!(egcd(240i64, 46), (2, -9, 47));
+        assert_eq!(egcd(17i128, 5), (1, -2, 7));
+        assert_eq!(egcd(0i64, 7), (7, 0, 1));
+    }
+
+    #[test]
+    fn modular_inverse() {
+        assert_eq!(mod_inv(3u64, 11), Some(4));
+        assert_eq!(mod_inv(-3i64, 11), Some(7));
+        assert_eq!(mod_inv(6u64, 9), None);
+        assert_eq!(mod_inv(5u64, 1), Some(0));
+        assert_eq!(mod_inv(5u64, 0), None);
+        assert_eq!(mod_inv(BigInt::from(5), BigInt::from(0)), None);
+        assert_eq!(
+            mod_inv(BigInt::from(42), BigInt::from(2017)),
+            Some(BigInt::from(1969))
+        );
+    }
+
+    #[test]
+    fn modular_exponentiation() {
+        assert_eq!(mod_pow(7u64, 8, 20201227), Some(5764801));
+        assert_eq!(mod_pow(2u64, 0, 1), Some(0));
+        assert_eq!(
+            mod_pow(3i128, 1_000_000_007, 998_244_353_000_000_007).map(BigInt::from),
+            mod_pow(
+                BigInt::from(3),
+                BigInt::from(1_000_000_007),
+                BigInt::from(998_244_353_000_000_007i64)
+            )
+        );
+        assert_eq!(mod_pow(2u64, 5, 0), None);
+        assert_eq!(mod_pow(2i64, 5, 0), None);
+        assert_eq!(mod_pow(2i64, -1, 11), Some(6));
+        assert_eq!(mod_pow(2i64, -3, 11), Some(7));
+        assert_eq!(mod_pow(3i64, -1, 9), None);
+    }
+
+    #[test]
+    fn chinese_remainder_theorem() {
+        assert_eq!(
+            chinese_remainder(&[(2u64, 3), (3, 5), (2, 7)]),
+            Some((23, 105))
+        );
+        assert_eq!(chinese_remainder(&[(-1i64, 4), (3, 6)]), Some((3, 12)));
+        assert_eq!(chinese_remainder(&[(1i64, 4), (2, 6)]), None);
+        assert_eq!(chinese_remainder::<i64>(&[]), Some((0, 1)));
+        assert_eq!(chinese_remainder(&[(2u64, 3), (1, 0)]), None);
+        assert_eq!(chinese_remainder(&[(0i64, 0)]), None);
+    }
+
+    #[test]
+    fn discrete_logarithm() {
+        assert_eq!(discrete_log(7u64, 5764801, 20201227), Some(8));
+        assert_eq!(discrete_log(7u64, 17807724, 20201227), Some(11));
+        assert_eq!(discrete_log(2i128, 1, 11), Some(0));
+        assert_eq!(discrete_log(2u64, 0, 11), None);
+        assert_eq!(discrete_log(2u64, 1, 0), None);
+        assert_eq!(discrete_log(2i64, 1, -11), None);
+        assert_eq!(
+            discrete_log(BigInt::from(3), BigInt::from(13), BigInt::from(17)),
+            Some(BigInt::from(4))
+        );
+    }
+}